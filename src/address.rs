@@ -1,9 +1,17 @@
 use std::str::FromStr;
 
 use kaspa_addresses::{Address, AddressError, Prefix, Version};
-use pyo3::{exceptions::PyException, prelude::*};
+use kaspa_consensus_core::network::NetworkType;
+use kaspa_txscript::standard::{extract_script_pub_key_address, pay_to_script_hash_script};
+use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
 use pyo3_stub_gen::derive::*;
 
+use crate::{
+    consensus::core::{network::PyNetworkType, script_public_key::PyScriptPublicKey},
+    types::PyBinary,
+    wallet::keys::publickey::PyPublicKey,
+};
+
 crate::wrap_unit_enum_for_py!(
     /// Kaspa Address version (`PubKey`, `PubKeyECDSA`, `ScriptHash`)
     ///-  PubKey addresses always have the version byte set to 0
@@ -92,6 +100,88 @@ impl PyAddress {
         Address::try_from(address).is_ok()
     }
 
+    /// Derive an address directly from a public key, without round-tripping
+    /// through string construction.
+    ///
+    /// Args:
+    ///     public_key: The public key to derive from.
+    ///     network: The network type for address encoding.
+    ///     version: Which address version to derive - `"PubKey"` (schnorr,
+    ///         default) or `"PubKeyECDSA"`.
+    ///
+    /// Returns:
+    ///     Address: The derived address.
+    ///
+    /// Raises:
+    ///     Exception: If address derivation fails, or `version` is
+    ///     `"ScriptHash"` (use `Address.from_script_hash` for that instead).
+    #[staticmethod]
+    #[pyo3(name = "from_public_key")]
+    #[pyo3(signature = (public_key, network, version=None))]
+    pub fn from_public_key(
+        public_key: PyPublicKey,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network: PyNetworkType,
+        #[gen_stub(override_type(type_repr = "str | AddressVersion | None = AddressVersion.PubKey"))]
+        version: Option<PyAddressVersion>,
+    ) -> PyResult<PyAddress> {
+        match version.unwrap_or(PyAddressVersion::PubKey) {
+            PyAddressVersion::PubKey => public_key.to_address(network),
+            PyAddressVersion::PubKeyECDSA => public_key.to_address_ecdsa(network),
+            PyAddressVersion::ScriptHash => Err(PyException::new_err(
+                "`ScriptHash` addresses are derived from a script, not a public key - use `Address.from_script_hash` instead",
+            )),
+        }
+    }
+
+    /// Derive a pay-to-script-hash (P2SH) address from a redeem script.
+    ///
+    /// Args:
+    ///     script: The redeem script bytes.
+    ///     network: The network type for address encoding.
+    ///
+    /// Returns:
+    ///     Address: The derived P2SH address.
+    ///
+    /// Raises:
+    ///     Exception: If address derivation fails.
+    #[staticmethod]
+    #[pyo3(name = "from_script_hash")]
+    pub fn from_script_hash(
+        script: PyBinary,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network: PyNetworkType,
+    ) -> PyResult<PyAddress> {
+        let script_public_key = pay_to_script_hash_script(&script.data);
+        let prefix = Prefix::from(NetworkType::from(network));
+        let address = extract_script_pub_key_address(&script_public_key, prefix)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(PyAddress(address))
+    }
+
+    /// Recover the address that a script public key pays to.
+    ///
+    /// Args:
+    ///     script_public_key: The locking script to pattern-match.
+    ///     prefix: The network prefix to encode the resulting address with.
+    ///
+    /// Returns:
+    ///     Address: The address paid to by `script_public_key`.
+    ///
+    /// Raises:
+    ///     Exception: If the script does not match a standard pay-to-address
+    ///     pattern (schnorr P2PK, ECDSA P2PK, or P2SH).
+    #[staticmethod]
+    #[pyo3(name = "from_script_public_key")]
+    pub fn from_script_public_key(
+        script_public_key: PyScriptPublicKey,
+        prefix: &str,
+    ) -> PyResult<PyAddress> {
+        let prefix =
+            Prefix::try_from(prefix).map_err(|err| PyException::new_err(err.to_string()))?;
+        let address = extract_script_pub_key_address(&script_public_key.into(), prefix)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(PyAddress(address))
+    }
+
     /// The string representation of the Address.
     ///
     /// Returns:
@@ -101,14 +191,42 @@ impl PyAddress {
         self.0.address_to_string()
     }
 
-    /// The string representation of the address version.
+    /// The address version.
     /// Versions are `PubKey`, `PubKeyECDSA`, or `ScriptHash`.
     ///
     /// Returns:
-    ///     str: The address version.
+    ///     AddressVersion: The address version.
     #[getter]
-    pub fn get_version(&self) -> String {
-        self.0.version.to_string()
+    pub fn get_version(&self) -> PyAddressVersion {
+        match self.0.version {
+            Version::PubKey => PyAddressVersion::PubKey,
+            Version::PubKeyECDSA => PyAddressVersion::PubKeyECDSA,
+            Version::ScriptHash => PyAddressVersion::ScriptHash,
+        }
+    }
+
+    /// Check whether this is a schnorr public-key (`PubKey`) address.
+    ///
+    /// Returns:
+    ///     bool: True if the address version is `PubKey`.
+    pub fn is_pubkey(&self) -> bool {
+        matches!(self.0.version, Version::PubKey)
+    }
+
+    /// Check whether this is an ECDSA public-key (`PubKeyECDSA`) address.
+    ///
+    /// Returns:
+    ///     bool: True if the address version is `PubKeyECDSA`.
+    pub fn is_ecdsa(&self) -> bool {
+        matches!(self.0.version, Version::PubKeyECDSA)
+    }
+
+    /// Check whether this is a pay-to-script-hash (`ScriptHash`) address.
+    ///
+    /// Returns:
+    ///     bool: True if the address version is `ScriptHash`.
+    pub fn is_script_hash(&self) -> bool {
+        matches!(self.0.version, Version::ScriptHash)
     }
 
     /// The network prefix of the address. Prefix is based on the network type (mainnet, testnet, etc..)
@@ -149,6 +267,14 @@ impl PyAddress {
         self.0.payload_to_string()
     }
 
+    /// The raw, decoded payload of the address.
+    ///
+    /// Returns:
+    ///     bytes: The address payload as raw bytes.
+    pub fn payload_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.payload)
+    }
+
     /// Get a shortened representation of the address.
     ///
     /// Args:
@@ -155,6 +155,65 @@ fn snake_to_pascal(s: &str) -> String {
         .collect()
 }
 
+/// RPC methods with a generated `Py<Name>Request` wrapper (see
+/// `rpc::model::define_py_request_types!`), keyed by the method name exactly
+/// as pyo3-stub-gen renders it (snake_case).
+///
+/// Driving `transform_rpc_method_line` from this list - rather than a
+/// hardcoded skip-list of name prefixes - keeps it from mis-detecting
+/// methods it doesn't actually have a typed request wrapper for.
+///
+/// This still only lets us *name* the right `XxxRequest`/`XxxResponse`
+/// TypedDicts, not populate their fields: `kaspa_rpc_core::message`'s
+/// request/response structs aren't schema-introspectable from this build
+/// step, so the field shapes still come from the hand-maintained
+/// `kaspa_rpc.pyi` appended by `append_rpc_types`, until the upstream crate
+/// exposes a machine-readable schema for this pass to consume instead.
+const RPC_METHODS_WITH_TYPED_REQUEST: &[&str] = &[
+    "get_block_count",
+    "get_block_dag_info",
+    "get_coin_supply",
+    "get_connected_peer_info",
+    "get_info",
+    "get_peer_addresses",
+    "get_metrics",
+    "get_connections",
+    "get_sink",
+    "get_sink_blue_score",
+    "ping",
+    "shutdown",
+    "get_server_info",
+    "get_sync_status",
+    "get_fee_estimate",
+    "get_current_network",
+    "get_system_info",
+    "add_peer",
+    "ban",
+    "estimate_network_hashes_per_second",
+    "get_balance_by_address",
+    "get_balances_by_addresses",
+    "get_block",
+    "get_blocks",
+    "get_block_template",
+    "get_current_block_color",
+    "get_daa_score_timestamp_estimate",
+    "get_fee_estimate_experimental",
+    "get_headers",
+    "get_mempool_entries",
+    "get_mempool_entries_by_addresses",
+    "get_mempool_entry",
+    "get_subnetwork",
+    "get_utxos_by_addresses",
+    "get_utxo_return_address",
+    "get_virtual_chain_from_block",
+    "get_virtual_chain_from_block_v2",
+    "resolve_finality_conflict",
+    "submit_block",
+    "submit_transaction",
+    "submit_transaction_replacement",
+    "unban",
+];
+
 /// Fixes RPC method signatures in the stub file to use proper TypedDict types.
 ///
 /// Transforms:
@@ -198,16 +257,9 @@ fn transform_rpc_method_line(line: &str) -> String {
 
     let method_name = &after_def[..paren_pos];
 
-    // Skip non-RPC methods (subscribe/unsubscribe, connect, disconnect, etc.)
-    if method_name.starts_with("subscribe")
-        || method_name.starts_with("unsubscribe")
-        || method_name == "connect"
-        || method_name == "disconnect"
-        || method_name == "start"
-        || method_name == "stop"
-        || method_name == "on"
-        || method_name == "remove_listener"
-    {
+    // Only methods with a generated `Py<Name>Request` wrapper have a real
+    // typed request/response pair to rewrite to.
+    if !RPC_METHODS_WITH_TYPED_REQUEST.contains(&method_name) {
         return line.to_string();
     }
 
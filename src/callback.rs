@@ -10,6 +10,7 @@ pub(crate) struct PyCallback {
     callback: Arc<Py<PyAny>>,
     args: Option<Arc<Py<PyTuple>>>,
     kwargs: Option<Arc<Py<PyDict>>>,
+    filter: Option<Arc<Py<PyAny>>>,
 }
 
 impl PyCallback {
@@ -18,13 +19,37 @@ impl PyCallback {
             callback: Arc::new(callback),
             args: Some(Arc::new(args)),
             kwargs: Some(Arc::new(kwargs)),
+            filter: None,
         }
     }
 
+    pub(crate) fn with_filter(mut self, filter: Option<Py<PyAny>>) -> Self {
+        self.filter = filter.map(Arc::new);
+        self
+    }
+
     pub(crate) fn callback_ptr_eq(&self, callback: &Py<PyAny>) -> bool {
         self.callback.as_ref().as_ptr() == callback.as_ptr()
     }
 
+    /// Evaluate the predicate filter (if any) against the normalized event
+    /// dict, logging and skipping the listener on predicate errors.
+    pub(crate) fn matches(&self, py: Python, event: &Bound<PyDict>) -> bool {
+        match &self.filter {
+            Some(filter) => match filter.bind(py).call1((event,)) {
+                Ok(result) => result.is_truthy().unwrap_or(false),
+                Err(err) => {
+                    workflow_log::log_error!(
+                        "UtxoProcessor: error while evaluating event filter: {}",
+                        err
+                    );
+                    false
+                }
+            },
+            None => true,
+        }
+    }
+
     fn add_event_to_args(&self, py: Python, event: Bound<PyDict>) -> PyResult<Py<PyTuple>> {
         match &self.args {
             Some(existing_args) => {
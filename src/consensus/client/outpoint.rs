@@ -1,7 +1,11 @@
 use crate::crypto::hashes::PyHash;
 use kaspa_consensus_client::{TransactionOutpoint, TransactionOutpointInner};
 use kaspa_consensus_core::tx::TransactionIndexType;
-use pyo3::{prelude::*, types::PyDict};
+use pyo3::{
+    exceptions::PyException,
+    prelude::*,
+    types::{PyBytes, PyDict, PyType},
+};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 
 /// Reference to a specific output in a previous transaction.
@@ -67,6 +71,35 @@ impl PyTransactionOutpoint {
             _ => false,
         }
     }
+
+    /// Serialize the outpoint to its compact binary form.
+    ///
+    /// Returns:
+    ///     bytes: The bincode-encoded outpoint.
+    ///
+    /// Raises:
+    ///     Exception: If serialization fails.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = bincode::serialize(&self.0).map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// Deserialize an outpoint from its compact binary form.
+    ///
+    /// Args:
+    ///     data: Bytes produced by `to_bytes`.
+    ///
+    /// Returns:
+    ///     TransactionOutpoint: The decoded outpoint.
+    ///
+    /// Raises:
+    ///     Exception: If `data` is not a valid encoded outpoint.
+    #[classmethod]
+    pub fn from_bytes(_cls: &Bound<'_, PyType>, data: &[u8]) -> PyResult<Self> {
+        let inner: TransactionOutpoint =
+            bincode::deserialize(data).map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(Self(inner))
+    }
 }
 
 impl From<PyTransactionOutpoint> for TransactionOutpoint {
@@ -5,14 +5,19 @@ use crate::consensus::core::network::PyNetworkType;
 use crate::crypto::hashes::PyHash;
 use crate::types::PyBinary;
 use kaspa_consensus_client::{Transaction, TransactionInner, TransactionInput, TransactionOutput};
+use kaspa_consensus_core::hashing::sighash::SigHashReusedValuesUnsync;
 use kaspa_consensus_core::network::NetworkType;
 use kaspa_consensus_core::subnets;
 use kaspa_consensus_core::subnets::SubnetworkId;
 use kaspa_consensus_core::tx as cctx;
-use kaspa_txscript::extract_script_pub_key_address;
+use kaspa_txscript::caches::Cache;
+use kaspa_txscript::{TxScriptEngine, extract_script_pub_key_address};
 use pyo3::prelude::*;
 use pyo3::types::PyType;
-use pyo3::{exceptions::PyException, types::PyDict};
+use pyo3::{
+    exceptions::PyException,
+    types::{PyBytes, PyDict},
+};
 use pyo3_stub_gen::derive::*;
 use workflow_core::hex::{FromHex, ToHex};
 
@@ -337,6 +342,51 @@ impl PyTransaction {
         Self::try_from(dict)
     }
 
+    /// Serialize the transaction to its compact binary form.
+    ///
+    /// Unlike `to_dict`, this uses bincode rather than `serde_pyobject` and is
+    /// intended for storing or transmitting many transactions efficiently.
+    ///
+    /// Returns:
+    ///     bytes: The bincode-encoded transaction.
+    ///
+    /// Raises:
+    ///     Exception: If serialization fails.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = bincode::serialize(self.0.inner())
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// Deserialize a transaction from its compact binary form.
+    ///
+    /// Args:
+    ///     data: Bytes produced by `to_bytes`.
+    ///
+    /// Returns:
+    ///     Transaction: The decoded transaction.
+    ///
+    /// Raises:
+    ///     Exception: If `data` is not a valid encoded transaction.
+    #[classmethod]
+    fn from_bytes(_cls: &Bound<'_, PyType>, data: &[u8]) -> PyResult<Self> {
+        let inner: TransactionInner =
+            bincode::deserialize(data).map_err(|err| PyException::new_err(err.to_string()))?;
+        let tx = Transaction::new(
+            Some(inner.id),
+            inner.version,
+            inner.inputs,
+            inner.outputs,
+            inner.lock_time,
+            inner.subnetwork_id,
+            inner.gas,
+            inner.payload,
+            inner.mass,
+        )
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(Self(tx))
+    }
+
     // Cannot be derived via pyclass(eq) as wrapped Transaction type does not derive PartialEq/Eq
     fn __eq__(&self, other: &PyTransaction) -> bool {
         match (bincode::serialize(&self.0), bincode::serialize(&other.0)) {
@@ -344,6 +394,128 @@ impl PyTransaction {
             _ => false,
         }
     }
+
+    /// Verify that every input's signature script satisfies its referenced
+    /// UTXO's script public key.
+    ///
+    /// Runs each input through the `kaspa_txscript` VM against the UTXO entry
+    /// recorded on that input (via `get_utxo()`), reusing sighash state across
+    /// inputs the way the node's own transaction validation does.
+    ///
+    /// Returns:
+    ///     VerifiedTransaction: A wrapper that is only constructible once
+    ///     every input has verified successfully.
+    ///
+    /// Raises:
+    ///     Exception: If an input has no populated UTXO entry, or if the
+    ///         script engine rejects an input, naming its index and the
+    ///         underlying VM error.
+    pub fn verify_signatures(&self) -> PyResult<PyVerifiedTransaction> {
+        let (cctx, utxos) = self
+            .0
+            .tx_and_utxos()
+            .map_err(|err| PyException::new_err(format!("Unable to resolve UTXO entries for verification: {err}")))?;
+        let populated_transaction = cctx::PopulatedTransaction::new(&cctx, utxos);
+
+        let sig_cache = Cache::new(10_000);
+        let mut reused_values = SigHashReusedValuesUnsync::new();
+        for (index, input) in populated_transaction.tx().inputs.iter().enumerate() {
+            let utxo_entry = populated_transaction
+                .utxo(index)
+                .ok_or_else(|| PyException::new_err(format!("Input {index} has no populated UTXO entry; call with get_utxo() set")))?;
+
+            let mut engine = TxScriptEngine::from_transaction_input(
+                &populated_transaction,
+                input,
+                index,
+                utxo_entry,
+                &mut reused_values,
+                &sig_cache,
+                false,
+            )
+            .map_err(|err| PyException::new_err(format!("Input {index} failed to initialize script engine: {err}")))?;
+
+            engine
+                .execute()
+                .map_err(|err| PyException::new_err(format!("Input {index} signature verification failed: {err}")))?;
+        }
+
+        Ok(PyVerifiedTransaction(self.0.clone()))
+    }
+}
+
+/// A transaction whose input signatures have all been verified.
+///
+/// Obtained only through `Transaction.verify_signatures()`; its existence is
+/// a guarantee that every input's signature script was checked against its
+/// UTXO entry. Exposes the same getters as `Transaction` but no setters, so
+/// a verified transaction cannot be silently mutated back into an unverified
+/// one.
+#[gen_stub_pyclass]
+#[pyclass(name = "VerifiedTransaction")]
+#[derive(Clone)]
+pub struct PyVerifiedTransaction(Transaction);
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyVerifiedTransaction {
+    /// The transaction ID (hash).
+    ///
+    /// Returns:
+    ///     str: The transaction ID as a hex string.
+    #[getter]
+    pub fn get_id(&self) -> String {
+        self.0.inner().id.to_string()
+    }
+
+    /// The list of transaction inputs.
+    ///
+    /// Returns:
+    ///     list[TransactionInput]: List of inputs spending previous outputs.
+    #[getter]
+    pub fn get_inputs(&self) -> PyResult<Vec<PyTransactionInput>> {
+        Ok(self
+            .0
+            .inner()
+            .inputs
+            .clone()
+            .into_iter()
+            .map(PyTransactionInput::from)
+            .collect())
+    }
+
+    /// The list of transaction outputs.
+    ///
+    /// Returns:
+    ///     list[TransactionOutput]: List of outputs defining value destinations.
+    #[getter]
+    pub fn get_outputs(&self) -> PyResult<Vec<PyTransactionOutput>> {
+        Ok(self
+            .0
+            .inner()
+            .outputs
+            .clone()
+            .into_iter()
+            .map(PyTransactionOutput::from)
+            .collect())
+    }
+
+    /// The transaction mass used for fee calculation.
+    ///
+    /// Returns:
+    ///     int: The transaction mass.
+    #[getter]
+    pub fn get_mass(&self) -> u64 {
+        self.0.inner().mass
+    }
+
+    /// Unwrap back into a plain, mutable `Transaction`.
+    ///
+    /// Returns:
+    ///     Transaction: A copy of the underlying transaction.
+    pub fn as_transaction(&self) -> PyTransaction {
+        PyTransaction(self.0.clone())
+    }
 }
 
 impl From<Transaction> for PyTransaction {
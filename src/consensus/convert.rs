@@ -1,4 +1,6 @@
-use kaspa_consensus_client::{TransactionOutpoint, UtxoEntryReference};
+use kaspa_consensus_client::{
+    Transaction, TransactionInput, TransactionOutput, TransactionOutpoint, UtxoEntries, UtxoEntry, UtxoEntryReference,
+};
 use kaspa_utils::hex::ToHex;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
@@ -72,8 +74,139 @@ impl ToPyDict for UtxoEntryReference {
     }
 }
 
-// ToPyDict for Transaction
-// ToPyDict for TransactionInput
-// ToPyDict for TransactionOutput
-// ToPyDict for UtxoEntry
-// ToPyDict for UtxoEntries
\ No newline at end of file
+impl ToPyDict for UtxoEntry {
+    fn to_py_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+
+        // Set `address` key
+        if let Some(addr) = self.address() {
+            dict.set_item("address", addr.to_string())?;
+        } else {
+            dict.set_item("address", py.None())?;
+        }
+
+        // Set `outpoint` key
+        dict.set_item("outpoint", serde_pyobject::to_pyobject(py, self.outpoint().inner())?)?;
+
+        // Set `amount` key
+        dict.set_item("amount", self.amount())?;
+
+        // Set `scriptPublicKey` key
+        dict.set_item(
+            "scriptPublicKey",
+            format!(
+                "{:02x}{}",
+                self.script_public_key().version(),
+                self.script_public_key().script().to_hex()
+            ),
+        )?;
+
+        // Set `blockDaaScore` key
+        dict.set_item("blockDaaScore", self.block_daa_score())?;
+
+        // Set `isCoinbase` key
+        dict.set_item("isCoinbase", self.is_coinbase())?;
+
+        Ok(dict)
+    }
+}
+
+impl ToPyDict for UtxoEntries {
+    fn to_py_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        let entries = self
+            .iter()
+            .map(|entry| entry.to_py_dict(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        dict.set_item("entries", entries)?;
+        Ok(dict)
+    }
+}
+
+impl ToPyDict for TransactionOutput {
+    fn to_py_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        let inner = self.inner();
+
+        // Set `value` key
+        dict.set_item("value", inner.value)?;
+
+        // Set `scriptPublicKey` key
+        dict.set_item(
+            "scriptPublicKey",
+            format!("{:02x}{}", inner.script_public_key.version(), inner.script_public_key.script().to_hex()),
+        )?;
+
+        Ok(dict)
+    }
+}
+
+impl ToPyDict for TransactionInput {
+    fn to_py_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        let inner = self.inner();
+
+        // Set `previousOutpoint` key
+        dict.set_item("previousOutpoint", inner.previous_outpoint.to_py_dict(py)?)?;
+
+        // Set `signatureScript` key
+        dict.set_item("signatureScript", inner.signature_script.to_hex())?;
+
+        // Set `sequence` key
+        dict.set_item("sequence", inner.sequence)?;
+
+        // Set `sigOpCount` key
+        dict.set_item("sigOpCount", inner.sig_op_count)?;
+
+        // Set `utxoEntry` key, resolved from the input's attached UTXO entry reference (if any)
+        match self.get_utxo() {
+            Some(utxo) => dict.set_item("utxoEntry", utxo.to_py_dict(py)?)?,
+            None => dict.set_item("utxoEntry", py.None())?,
+        }
+
+        Ok(dict)
+    }
+}
+
+impl ToPyDict for Transaction {
+    fn to_py_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        let inner = self.inner();
+
+        // Set `id` key
+        dict.set_item("id", inner.id.to_string())?;
+
+        // Set `version` key
+        dict.set_item("version", inner.version)?;
+
+        // Set `inputs` key
+        let inputs = inner
+            .inputs
+            .iter()
+            .map(|input| input.to_py_dict(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        dict.set_item("inputs", inputs)?;
+
+        // Set `outputs` key
+        let outputs = inner
+            .outputs
+            .iter()
+            .map(|output| output.to_py_dict(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        dict.set_item("outputs", outputs)?;
+
+        // Set `lockTime` key
+        dict.set_item("lockTime", inner.lock_time)?;
+
+        // Set `subnetworkId` key
+        dict.set_item("subnetworkId", inner.subnetwork_id.to_string())?;
+
+        // Set `gas` key
+        dict.set_item("gas", inner.gas)?;
+
+        // Set `payload` key
+        dict.set_item("payload", inner.payload.to_hex())?;
+
+        Ok(dict)
+    }
+}
\ No newline at end of file
@@ -1,8 +1,10 @@
+use crate::address::PyAddress;
 use crate::types::PyBinary;
 use kaspa_consensus_core::tx::ScriptPublicKey;
+use kaspa_txscript::standard::pay_to_address_script;
 use kaspa_utils::hex::FromHex;
 use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
-use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyfunction, gen_stub_pymethods};
 use std::str::FromStr;
 
 /// A script public key.
@@ -54,6 +56,19 @@ impl PyScriptPublicKey {
     pub fn __bytes__<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
         PyBytes::new(py, self.0.script())
     }
+
+    /// Build the locking script that pays to `address`.
+    ///
+    /// Args:
+    ///     address: The destination address.
+    ///
+    /// Returns:
+    ///     ScriptPublicKey: The pay-to-address script for `address`'s version -
+    ///     schnorr P2PK, ECDSA P2PK, or P2SH, matching its `AddressVersion`.
+    #[staticmethod]
+    pub fn from_address(address: PyAddress) -> Self {
+        Self(pay_to_address_script(&address.into()))
+    }
 }
 
 impl From<PyScriptPublicKey> for ScriptPublicKey {
@@ -77,3 +92,18 @@ impl FromHex for PyScriptPublicKey {
         Ok(Self(inner))
     }
 }
+
+/// Build the locking script that pays to `address`.
+///
+/// Args:
+///     address: The destination address.
+///
+/// Returns:
+///     ScriptPublicKey: The pay-to-address script for `address`'s version -
+///     schnorr P2PK, ECDSA P2PK, or P2SH, matching its `AddressVersion`.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "pay_to_address_script")]
+pub fn py_pay_to_address_script(address: PyAddress) -> PyScriptPublicKey {
+    PyScriptPublicKey::from_address(address)
+}
@@ -0,0 +1,130 @@
+use crate::{types::PyBinary, wallet::keys::publickey::PyPublicKey};
+use aes_gcm::{
+    Aes256Gcm, Key, Nonce,
+    aead::{Aead, KeyInit},
+};
+use hkdf::Hkdf;
+use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use rand::rngs::OsRng;
+use sha2::Sha256;
+
+/// Length, in bytes, of the AES-256-GCM key and nonce derived via HKDF-SHA256.
+const AES_KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+/// Derive a 32-byte AES key from an ECDH shared point via HKDF-SHA256.
+fn derive_key(shared_point: &secp256k1::PublicKey) -> PyResult<[u8; AES_KEY_LEN]> {
+    let hk = Hkdf::<Sha256>::new(None, &shared_point.serialize());
+    let mut key = [0u8; AES_KEY_LEN];
+    hk.expand(b"kaspa-ecies", &mut key)
+        .map_err(|err| PyException::new_err(format!("HKDF expansion failed: {err}")))?;
+    Ok(key)
+}
+
+/// Encrypt bytes to a recipient's secp256k1 public key using ECIES.
+///
+/// Generates an ephemeral keypair, computes the ECDH shared point with the
+/// recipient's public key, derives an AES-256-GCM key from it via
+/// HKDF-SHA256, and encrypts `plaintext` with a random 12-byte nonce. The
+/// output is `ephemeral_public_key (33 bytes) || nonce (12 bytes) || ciphertext`,
+/// so only the recipient's matching private key can decrypt it.
+///
+/// Args:
+///     recipient_public_key: The recipient's compressed secp256k1 public key.
+///     plaintext: The bytes to encrypt.
+///
+/// Returns:
+///     bytes: The encoded ciphertext, ready to pass to `decrypt_to_private_key`.
+///
+/// Raises:
+///     Exception: If the recipient key is invalid or encryption fails.
+///
+/// Category: Wallet/Keys
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "encrypt_to_public_key")]
+pub fn py_encrypt_to_public_key<'py>(
+    py: Python<'py>,
+    recipient_public_key: PyPublicKey,
+    plaintext: PyBinary,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let recipient = recipient_public_key
+        .0
+        .public_key
+        .ok_or_else(|| PyException::new_err("ECIES requires a full (non x-only) public key"))?;
+
+    let (ephemeral_secret, ephemeral_public) = secp256k1::generate_keypair(&mut OsRng);
+    let shared_point = recipient
+        .mul_tweak(secp256k1::SECP256K1, &secp256k1::Scalar::from(ephemeral_secret))
+        .map_err(|err| PyException::new_err(format!("ECDH failed: {err}")))?;
+
+    let key = derive_key(&shared_point)?;
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    rand::RngCore::fill_bytes(&mut OsRng, &mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext.data.as_slice())
+        .map_err(|err| PyException::new_err(format!("Encryption failed: {err}")))?;
+
+    let mut output = ephemeral_public.serialize().to_vec();
+    output.extend_from_slice(&nonce_bytes);
+    output.extend_from_slice(&ciphertext);
+    Ok(PyBytes::new(py, &output))
+}
+
+/// Decrypt bytes produced by `encrypt_to_public_key` using the matching
+/// private key.
+///
+/// Args:
+///     recipient_secret_key: The recipient's 32-byte secp256k1 secret key.
+///     ciphertext: The encoded blob produced by `encrypt_to_public_key`.
+///
+/// Returns:
+///     bytes: The recovered plaintext.
+///
+/// Raises:
+///     Exception: If the blob is malformed, the key does not match, or
+///         authentication fails.
+///
+/// Category: Wallet/Keys
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "decrypt_to_private_key")]
+pub fn py_decrypt_to_private_key<'py>(
+    py: Python<'py>,
+    recipient_secret_key: PyBinary,
+    ciphertext: PyBinary,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let secret_key = secp256k1::SecretKey::from_slice(&recipient_secret_key.data)
+        .map_err(|err| PyException::new_err(format!("Invalid secret key: {err}")))?;
+
+    let data = ciphertext.data;
+    if data.len() < 33 + NONCE_LEN {
+        return Err(PyException::new_err(
+            "ciphertext is too short to contain an ephemeral public key and nonce",
+        ));
+    }
+    let (ephemeral_bytes, rest) = data.split_at(33);
+    let (nonce_bytes, encrypted) = rest.split_at(NONCE_LEN);
+
+    let ephemeral_public = secp256k1::PublicKey::from_slice(ephemeral_bytes)
+        .map_err(|err| PyException::new_err(format!("Invalid ephemeral public key: {err}")))?;
+
+    let shared_point = ephemeral_public
+        .mul_tweak(secp256k1::SECP256K1, &secp256k1::Scalar::from(secret_key))
+        .map_err(|err| PyException::new_err(format!("ECDH failed: {err}")))?;
+
+    let key = derive_key(&shared_point)?;
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    let nonce = Nonce::from_slice(nonce_bytes);
+
+    let plaintext = cipher
+        .decrypt(nonce, encrypted)
+        .map_err(|_| PyException::new_err("Decryption failed: wrong key or corrupted ciphertext"))?;
+
+    Ok(PyBytes::new(py, &plaintext))
+}
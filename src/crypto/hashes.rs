@@ -1,5 +1,6 @@
 use kaspa_hashes::Hash;
 use pyo3::{exceptions::PyException, prelude::*};
+use pyo3::types::{PyBytes, PyType};
 use pyo3_stub_gen::derive::*;
 use std::str::FromStr;
 
@@ -41,6 +42,34 @@ impl PyHash {
     pub fn py_to_string(&self) -> String {
         self.0.to_string()
     }
+
+    /// Serialize the hash to its compact binary form.
+    ///
+    /// Returns:
+    ///     bytes: The 32-byte hash, bincode-encoded.
+    ///
+    /// Raises:
+    ///     Exception: If serialization fails.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = bincode::serialize(&self.0).map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// Deserialize a hash from its compact binary form.
+    ///
+    /// Args:
+    ///     data: Bytes produced by `to_bytes`.
+    ///
+    /// Returns:
+    ///     Hash: The decoded hash.
+    ///
+    /// Raises:
+    ///     Exception: If `data` is not a valid encoded hash.
+    #[classmethod]
+    pub fn from_bytes(_cls: &Bound<'_, PyType>, data: &[u8]) -> PyResult<Self> {
+        let inner: Hash = bincode::deserialize(data).map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(Self(inner))
+    }
 }
 
 impl From<PyHash> for Hash {
@@ -0,0 +1,72 @@
+use crate::crypto::keystore;
+use pyo3::exceptions::PyException;
+use pyo3::{PyErr, PyResult};
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// Current on-disk key file format version, bumped on any breaking change to
+/// the envelope shape below.
+const FILE_VERSION: u32 = 1;
+
+/// The on-disk envelope written by `write_to_file` and read by
+/// `read_from_file`, shared by `XPrv`, `PrivateKey`, and `Mnemonic`.
+///
+/// `kind` lets a single loader dispatch to (or reject) the right type, and
+/// `encrypted` says whether `data` is the canonical key string verbatim or a
+/// `keystore::encrypt` envelope that still needs a password to open.
+#[derive(Serialize, Deserialize)]
+struct KeyFile {
+    kind: String,
+    version: u32,
+    encrypted: bool,
+    data: String,
+}
+
+fn pyerr(message: impl Into<String>) -> PyErr {
+    PyException::new_err(message.into())
+}
+
+/// Write a key's canonical string form to `path`, as the given `kind`.
+///
+/// When `password` is given, `data` is routed through the encrypted-keystore
+/// format (PBKDF2-HMAC-SHA512 + AES-256-GCM); otherwise it is stored as
+/// plain text.
+pub fn write_to_file(path: &str, kind: &str, canonical: &str, password: Option<&str>) -> PyResult<()> {
+    let (encrypted, data) = match password {
+        Some(password) => (true, keystore::encrypt(canonical.as_bytes(), password)?),
+        None => (false, canonical.to_string()),
+    };
+
+    let file = KeyFile { kind: kind.to_string(), version: FILE_VERSION, encrypted, data };
+    let json = serde_json::to_string_pretty(&file)
+        .map_err(|err| pyerr(format!("Failed to serialize key file: {err}")))?;
+    fs::write(path, json).map_err(|err| pyerr(format!("Failed to write `{path}`: {err}")))
+}
+
+/// Read a key's canonical string form back from `path`.
+///
+/// Raises if the file's `kind` does not match `expected_kind` (no silent
+/// coercion between key types) or, for an encrypted file, if `password` is
+/// missing or wrong.
+pub fn read_from_file(path: &str, expected_kind: &str, password: Option<&str>) -> PyResult<String> {
+    let json = fs::read_to_string(path).map_err(|err| pyerr(format!("Failed to read `{path}`: {err}")))?;
+    let file: KeyFile =
+        serde_json::from_str(&json).map_err(|err| pyerr(format!("Invalid key file `{path}`: {err}")))?;
+
+    if file.kind != expected_kind {
+        return Err(pyerr(format!(
+            "Key file `{path}` contains a `{}` key, expected `{expected_kind}`",
+            file.kind
+        )));
+    }
+
+    if file.encrypted {
+        let password = password
+            .ok_or_else(|| pyerr(format!("Key file `{path}` is encrypted - a password is required")))?;
+        let plaintext = keystore::decrypt(&file.data, password)?;
+        String::from_utf8(plaintext)
+            .map_err(|err| pyerr(format!("Decrypted key file `{path}` is not valid UTF-8: {err}")))
+    } else {
+        Ok(file.data)
+    }
+}
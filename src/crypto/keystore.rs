@@ -0,0 +1,112 @@
+use aes_gcm::{
+    Aes256Gcm, Key, Nonce,
+    aead::{Aead, KeyInit},
+};
+use base64::Engine as _;
+use pbkdf2::pbkdf2_hmac;
+use pyo3::exceptions::PyException;
+use pyo3::PyErr;
+use rand::{RngCore, rngs::OsRng};
+use serde::{Deserialize, Serialize};
+use sha2::Sha512;
+
+/// PBKDF2-HMAC-SHA512 iteration count for password-based key derivation.
+///
+/// High enough to make brute-forcing a weak password costly, in line with
+/// current guidance for PBKDF2-HMAC-SHA512 keystores.
+const PBKDF2_ITERATIONS: u32 = 210_000;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+const CIPHER_ID: &str = "aes-256-gcm";
+const KDF_ID: &str = "pbkdf2-hmac-sha512";
+
+/// A self-describing, portable encrypted keystore envelope.
+///
+/// Serializes to JSON so a keystore blob can be persisted at rest and moved
+/// between hosts without any out-of-band knowledge of the parameters used to
+/// produce it.
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    cipher: String,
+    kdf: String,
+    iterations: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+fn pyerr(message: impl Into<String>) -> PyErr {
+    PyException::new_err(message.into())
+}
+
+fn derive_key(password: &str, salt: &[u8], iterations: u32) -> [u8; KEY_LEN] {
+    let mut key = [0u8; KEY_LEN];
+    pbkdf2_hmac::<Sha512>(password.as_bytes(), salt, iterations, &mut key);
+    key
+}
+
+/// Encrypt `plaintext` into a self-describing JSON keystore envelope.
+///
+/// A 32-byte key is derived from `password` via PBKDF2-HMAC-SHA512 with a
+/// random 16-byte salt, then used to encrypt `plaintext` with AES-256-GCM
+/// under a random 12-byte nonce. The envelope carries the salt, nonce,
+/// iteration count, and cipher/KDF identifiers needed to reverse the process.
+pub fn encrypt(plaintext: &[u8], password: &str) -> PyResult<String> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let key = derive_key(password, &salt, PBKDF2_ITERATIONS);
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+        .map_err(|err| pyerr(format!("Encryption failed: {err}")))?;
+
+    let envelope = Envelope {
+        cipher: CIPHER_ID.to_string(),
+        kdf: KDF_ID.to_string(),
+        iterations: PBKDF2_ITERATIONS,
+        salt: base64::engine::general_purpose::STANDARD.encode(salt),
+        nonce: base64::engine::general_purpose::STANDARD.encode(nonce_bytes),
+        ciphertext: base64::engine::general_purpose::STANDARD.encode(ciphertext),
+    };
+
+    serde_json::to_string(&envelope).map_err(|err| pyerr(format!("Failed to serialize keystore: {err}")))
+}
+
+/// Decrypt a keystore envelope produced by `encrypt`.
+///
+/// Raises a `PyException` (rather than returning garbage) if `password` is
+/// wrong, since AES-GCM authentication fails before any plaintext is
+/// returned.
+pub fn decrypt(blob: &str, password: &str) -> PyResult<Vec<u8>> {
+    let envelope: Envelope =
+        serde_json::from_str(blob).map_err(|err| pyerr(format!("Invalid keystore envelope: {err}")))?;
+
+    if envelope.cipher != CIPHER_ID || envelope.kdf != KDF_ID {
+        return Err(pyerr(format!(
+            "Unsupported keystore cipher/KDF: {}/{}",
+            envelope.cipher, envelope.kdf
+        )));
+    }
+
+    let salt = base64::engine::general_purpose::STANDARD
+        .decode(&envelope.salt)
+        .map_err(|err| pyerr(format!("Invalid keystore salt: {err}")))?;
+    let nonce_bytes = base64::engine::general_purpose::STANDARD
+        .decode(&envelope.nonce)
+        .map_err(|err| pyerr(format!("Invalid keystore nonce: {err}")))?;
+    let ciphertext = base64::engine::general_purpose::STANDARD
+        .decode(&envelope.ciphertext)
+        .map_err(|err| pyerr(format!("Invalid keystore ciphertext: {err}")))?;
+
+    let key = derive_key(password, &salt, envelope.iterations);
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    cipher
+        .decrypt(Nonce::from_slice(&nonce_bytes), ciphertext.as_slice())
+        .map_err(|_| pyerr("Decryption failed: wrong password or corrupted keystore"))
+}
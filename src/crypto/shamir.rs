@@ -0,0 +1,208 @@
+use pyo3::exceptions::PyException;
+use pyo3::PyErr;
+use sha2::{Digest, Sha256};
+
+/// AES irreducible polynomial (x^8 + x^4 + x^3 + x + 1) used for GF(2^8) arithmetic.
+const GF_POLY: u16 = 0x11B;
+
+/// Length, in bytes, of the SHA-256 digest appended to a split secret so
+/// `combine` can detect an incorrect or inconsistent set of shares.
+const DIGEST_LEN: usize = 32;
+
+/// log/antilog tables for GF(2^8) multiplication, generated once from the
+/// generator `3` (the conventional choice for the AES field).
+struct GfTables {
+    exp: [u8; 255],
+    log: [u8; 256],
+}
+
+fn gf_tables() -> GfTables {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut x: u16 = 1;
+    for i in 0..255 {
+        exp[i] = x as u8;
+        log[x as usize] = i as u8;
+        // Multiply by the generator `3` (i.e. `x ^ (x << 1)`), which is a
+        // primitive root of GF(2^8) under `GF_POLY` - unlike `2`, whose
+        // multiplicative order is only 51, `3`'s order is the full 255.
+        let doubled = {
+            let mut d = x << 1;
+            if d & 0x100 != 0 {
+                d ^= GF_POLY;
+            }
+            d
+        };
+        x ^= doubled;
+    }
+    GfTables { exp, log }
+}
+
+fn gf_mul(tables: &GfTables, a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    let sum = tables.log[a as usize] as u16 + tables.log[b as usize] as u16;
+    tables.exp[(sum % 255) as usize]
+}
+
+fn gf_div(tables: &GfTables, a: u8, b: u8) -> u8 {
+    assert!(b != 0, "division by zero in GF(2^8)");
+    if a == 0 {
+        return 0;
+    }
+    let diff = (tables.log[a as usize] as i16 - tables.log[b as usize] as i16).rem_euclid(255);
+    tables.exp[diff as usize]
+}
+
+/// Evaluate the degree-`t-1` polynomial (given by its coefficients, constant
+/// term first) at `x` over GF(2^8).
+fn gf_eval(tables: &GfTables, coefficients: &[u8], x: u8) -> u8 {
+    let mut result = 0u8;
+    for &coefficient in coefficients.iter().rev() {
+        result = gf_mul(tables, result, x) ^ coefficient;
+    }
+    result
+}
+
+fn pyerr(message: impl Into<String>) -> PyErr {
+    PyException::new_err(message.into())
+}
+
+/// Split `secret` into `shares` shares such that any `threshold` of them can
+/// reconstruct it, using Shamir's secret sharing over GF(2^8). Each returned
+/// share is `[x_index] || share_bytes || sha256(secret)`, so `combine` can
+/// both identify which shares go together and detect a bad combination.
+pub fn split(secret: &[u8], threshold: u8, shares: u8) -> Result<Vec<Vec<u8>>, PyErr> {
+    if threshold < 2 {
+        return Err(pyerr("`threshold` must be at least 2"));
+    }
+    if threshold > shares {
+        return Err(pyerr("`threshold` must not exceed the number of shares"));
+    }
+    if shares == 0 || shares as usize > 255 {
+        return Err(pyerr("`shares` must be between 1 and 255"));
+    }
+    if secret.is_empty() {
+        return Err(pyerr("`secret` must not be empty"));
+    }
+
+    let tables = gf_tables();
+    let digest = Sha256::digest(secret);
+
+    let mut coefficients_per_byte = Vec::with_capacity(secret.len());
+    for &byte in secret {
+        let mut coefficients = vec![0u8; threshold as usize];
+        coefficients[0] = byte;
+        for coefficient in coefficients.iter_mut().skip(1) {
+            *coefficient = rand::random::<u8>();
+        }
+        coefficients_per_byte.push(coefficients);
+    }
+
+    let mut results = Vec::with_capacity(shares as usize);
+    for x in 1..=shares {
+        let mut share = Vec::with_capacity(1 + secret.len() + DIGEST_LEN);
+        share.push(x);
+        for coefficients in &coefficients_per_byte {
+            share.push(gf_eval(&tables, coefficients, x));
+        }
+        share.extend_from_slice(&digest);
+        results.push(share);
+    }
+    Ok(results)
+}
+
+/// Reconstruct the original secret from `threshold`-or-more shares produced
+/// by `split`, via Lagrange interpolation at `x = 0` over GF(2^8).
+pub fn combine(shares: &[Vec<u8>]) -> Result<Vec<u8>, PyErr> {
+    if shares.len() < 2 {
+        return Err(pyerr("at least 2 shares are required to combine"));
+    }
+
+    let share_len = shares[0].len();
+    if share_len <= 1 + DIGEST_LEN {
+        return Err(pyerr("share is too short to contain a secret"));
+    }
+    if shares.iter().any(|share| share.len() != share_len) {
+        return Err(pyerr("all shares must have the same length"));
+    }
+
+    let mut seen_x = std::collections::HashSet::new();
+    for share in shares {
+        if !seen_x.insert(share[0]) {
+            return Err(pyerr("duplicate share index found among the provided shares"));
+        }
+    }
+
+    let tables = gf_tables();
+    let secret_len = share_len - 1 - DIGEST_LEN;
+    let mut secret = vec![0u8; secret_len];
+
+    for (byte_index, secret_byte) in secret.iter_mut().enumerate() {
+        let mut value = 0u8;
+        for i in 0..shares.len() {
+            let (xi, yi) = (shares[i][0], shares[i][1 + byte_index]);
+            let mut numerator = 1u8;
+            let mut denominator = 1u8;
+            for (j, share) in shares.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let xj = share[0];
+                numerator = gf_mul(&tables, numerator, xj);
+                denominator = gf_mul(&tables, denominator, xi ^ xj);
+            }
+            let lagrange_coefficient = gf_div(&tables, numerator, denominator);
+            value ^= gf_mul(&tables, yi, lagrange_coefficient);
+        }
+        *secret_byte = value;
+    }
+
+    let digest = &shares[0][1 + secret_len..];
+    if Sha256::digest(&secret).as_slice() != digest {
+        return Err(pyerr(
+            "combined secret's checksum does not match - shares are inconsistent or incorrect",
+        ));
+    }
+
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gf_tables_use_a_full_order_generator() {
+        let tables = gf_tables();
+        let mut seen = std::collections::HashSet::new();
+        for &value in &tables.exp {
+            seen.insert(value);
+        }
+        // A primitive root's powers cover every one of the 255 nonzero byte values.
+        assert_eq!(seen.len(), 255);
+    }
+
+    #[test]
+    fn split_combine_round_trips_across_thresholds_and_share_counts() {
+        let secret = b"correct horse battery staple".to_vec();
+        for (threshold, shares) in [(2, 3), (3, 5), (5, 5), (2, 255)] {
+            let split_shares = split(&secret, threshold, shares).unwrap();
+            assert_eq!(split_shares.len(), shares as usize);
+
+            let subset: Vec<Vec<u8>> = split_shares.into_iter().take(threshold as usize).collect();
+            let recovered = combine(&subset).unwrap();
+            assert_eq!(recovered, secret, "threshold={threshold}, shares={shares}");
+        }
+    }
+
+    #[test]
+    fn combine_rejects_inconsistent_shares() {
+        let secret = b"another secret".to_vec();
+        let mut shares_a = split(&secret, 2, 3).unwrap();
+        let shares_b = split(b"a different secret", 2, 3).unwrap();
+        shares_a[0] = shares_b[0].clone();
+        assert!(combine(&shares_a[..2]).is_err());
+    }
+}
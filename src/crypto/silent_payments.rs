@@ -0,0 +1,366 @@
+use crate::{
+    address::PyAddress,
+    consensus::{client::outpoint::PyTransactionOutpoint, core::network::PyNetworkType},
+    wallet::keys::{keypair::PyKeypair, privatekey::PyPrivateKey, publickey::PyPublicKey},
+};
+use kaspa_addresses::{Address, Version};
+use kaspa_consensus_core::network::NetworkType;
+use pyo3::{exceptions::PyException, prelude::*};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+use workflow_core::hex::FromHex;
+use zeroize::Zeroize;
+
+/// A recipient's silent-payment address: a scan key and a spend key, neither
+/// of which is ever reused or published as a regular on-chain `Address`.
+///
+/// Senders derive a fresh, unlinkable destination `Address` per payment from
+/// these two keys via [`SilentPayment::generate_recipient_addresses`].
+///
+/// Category: Wallet/Keys
+#[gen_stub_pyclass]
+#[pyclass(name = "SilentPaymentAddress")]
+#[derive(Clone)]
+pub struct PySilentPaymentAddress {
+    scan_public_key: secp256k1::XOnlyPublicKey,
+    spend_public_key: secp256k1::XOnlyPublicKey,
+    network_type: NetworkType,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PySilentPaymentAddress {
+    /// Build a silent-payment address from a recipient's scan and spend keypairs.
+    ///
+    /// Args:
+    ///     scan: The recipient's scan keypair (`B_scan`). Kept online to detect payments.
+    ///     spend: The recipient's spend keypair (`B_spend`). Kept offline to move funds.
+    ///     network: The network type the derived addresses will belong to.
+    ///
+    /// Returns:
+    ///     SilentPaymentAddress: The recipient's reusable silent-payment address.
+    ///
+    /// Raises:
+    ///     Exception: If either keypair's public key is malformed.
+    #[staticmethod]
+    #[pyo3(signature = (scan, spend, network))]
+    pub fn from_keypairs(
+        scan: &PyKeypair,
+        spend: &PyKeypair,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network: PyNetworkType,
+    ) -> PyResult<Self> {
+        let scan_public_key = secp256k1::XOnlyPublicKey::from_str(&scan.get_xonly_public_key())
+            .map_err(|err| PyException::new_err(format!("Invalid scan key: {err}")))?;
+        let spend_public_key = secp256k1::XOnlyPublicKey::from_str(&spend.get_xonly_public_key())
+            .map_err(|err| PyException::new_err(format!("Invalid spend key: {err}")))?;
+
+        Ok(PySilentPaymentAddress {
+            scan_public_key,
+            spend_public_key,
+            network_type: NetworkType::from(network),
+        })
+    }
+
+    /// The recipient's scan public key (`B_scan`) as hex.
+    #[getter]
+    pub fn get_scan_public_key(&self) -> String {
+        self.scan_public_key.to_string()
+    }
+
+    /// The recipient's spend public key (`B_spend`) as hex.
+    #[getter]
+    pub fn get_spend_public_key(&self) -> String {
+        self.spend_public_key.to_string()
+    }
+
+    /// Encode this address as a single string for sharing out of band.
+    ///
+    /// Returns:
+    ///     str: `"sp1:" || scan_public_key || spend_public_key`, hex-encoded.
+    pub fn encode(&self) -> String {
+        format!(
+            "sp1:{}{}",
+            self.scan_public_key, self.spend_public_key
+        )
+    }
+
+    /// Decode an address produced by `encode`.
+    ///
+    /// Args:
+    ///     data: The encoded address string.
+    ///     network: The network type the derived addresses will belong to.
+    ///
+    /// Raises:
+    ///     Exception: If `data` is not a valid encoded silent-payment address.
+    #[staticmethod]
+    #[pyo3(signature = (data, network))]
+    pub fn decode(
+        data: &str,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network: PyNetworkType,
+    ) -> PyResult<Self> {
+        let body = data
+            .strip_prefix("sp1:")
+            .ok_or_else(|| PyException::new_err("Not a silent-payment address: missing 'sp1:' prefix"))?;
+        if body.len() != 128 {
+            return Err(PyException::new_err(
+                "Not a silent-payment address: expected 64 bytes of hex-encoded scan + spend keys",
+            ));
+        }
+        let (scan_hex, spend_hex) = body.split_at(64);
+        let scan_public_key = secp256k1::XOnlyPublicKey::from_str(scan_hex)
+            .map_err(|err| PyException::new_err(format!("Invalid scan key: {err}")))?;
+        let spend_public_key = secp256k1::XOnlyPublicKey::from_str(spend_hex)
+            .map_err(|err| PyException::new_err(format!("Invalid spend key: {err}")))?;
+
+        Ok(PySilentPaymentAddress {
+            scan_public_key,
+            spend_public_key,
+            network_type: NetworkType::from(network),
+        })
+    }
+}
+
+/// Sender- and recipient-side silent-payment output derivation.
+///
+/// Category: Wallet/Keys
+#[gen_stub_pyclass]
+#[pyclass(name = "SilentPayment")]
+pub struct PySilentPayment {}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PySilentPayment {
+    /// Derive `count` destination addresses for a payment to `recipient_address`.
+    ///
+    /// Sums `input_private_keys` into `a_sum`, derives the matching `A_sum`,
+    /// and combines them with the lowest-sorted of `input_outpoints` and the
+    /// recipient's scan key into a shared secret `ecdh`. The k-th returned
+    /// address is `B_spend + H("SharedSecret" || ecdh || k)·G`, so paying the
+    /// same recipient `count` times produces `count` unlinkable addresses
+    /// that only the recipient can recognize and spend.
+    ///
+    /// Args:
+    ///     input_private_keys: The private keys of every input being spent in
+    ///         this payment's transaction.
+    ///     input_outpoints: The outpoints of those same inputs, in the same order.
+    ///     recipient_address: The recipient's silent-payment address.
+    ///     count: The number of destination addresses to derive.
+    ///
+    /// Returns:
+    ///     list[Address]: `count` fresh addresses paying `recipient_address`.
+    ///
+    /// Raises:
+    ///     Exception: If `input_private_keys`/`input_outpoints` are empty or
+    ///         of mismatched length, if the summed input key is zero, or if
+    ///         key derivation otherwise fails.
+    #[staticmethod]
+    #[pyo3(signature = (input_private_keys, input_outpoints, recipient_address, count))]
+    pub fn generate_recipient_addresses(
+        input_private_keys: Vec<PyPrivateKey>,
+        input_outpoints: Vec<PyTransactionOutpoint>,
+        recipient_address: &PySilentPaymentAddress,
+        count: u32,
+    ) -> PyResult<Vec<PyAddress>> {
+        if input_private_keys.is_empty() || input_outpoints.is_empty() {
+            return Err(PyException::new_err(
+                "silent payments require at least one input private key and outpoint",
+            ));
+        }
+        if input_private_keys.len() != input_outpoints.len() {
+            return Err(PyException::new_err(
+                "input_private_keys and input_outpoints must have the same length",
+            ));
+        }
+
+        let mut a_sum = sum_secret_keys(&input_private_keys)?;
+        let a_sum_key = secp256k1::SecretKey::from_slice(&a_sum)
+            .map_err(|_| PyException::new_err("summed input private keys are zero"))?;
+        let a_sum_public = secp256k1::PublicKey::from_secret_key(secp256k1::SECP256K1, &a_sum_key);
+
+        let input_hash = compute_input_hash(&input_outpoints, &a_sum_public)?;
+
+        let ecdh = recipient_address
+            .scan_public_key_full()
+            .mul_tweak(secp256k1::SECP256K1, &scalar_product(&input_hash, &a_sum_key)?)
+            .map_err(|err| PyException::new_err(format!("ECDH failed: {err}")))?;
+        a_sum.zeroize();
+
+        let network = recipient_address.network_type;
+        let mut addresses = Vec::with_capacity(count as usize);
+        for k in 0..count {
+            let destination = derive_destination_key(&ecdh, k, &recipient_address.spend_public_key)?;
+            addresses.push(Address::new(network.into(), Version::PubKey, &destination.serialize()).into());
+        }
+        Ok(addresses)
+    }
+
+    /// Recompute the same `count` destination addresses from the recipient's
+    /// side, so a wallet can check them against a transaction's outputs
+    /// without the sender's private keys.
+    ///
+    /// Args:
+    ///     scan: The recipient's scan keypair (`b_scan`).
+    ///     spend_public_key: The recipient's spend public key (`B_spend`), as hex.
+    ///     input_public_keys: The public keys of every input in the payment
+    ///         transaction (e.g. recovered from its signature scripts).
+    ///     input_outpoints: The outpoints of those same inputs, in the same order.
+    ///     count: The number of destination addresses to derive.
+    ///     network: The network type the derived addresses belong to.
+    ///
+    /// Returns:
+    ///     list[Address]: `count` addresses to look for among the
+    ///     transaction's outputs.
+    ///
+    /// Raises:
+    ///     Exception: If the inputs are empty/mismatched, the summed input
+    ///         key is zero (the point at infinity), or key derivation fails.
+    #[staticmethod]
+    #[pyo3(signature = (scan, spend_public_key, input_public_keys, input_outpoints, count, network))]
+    pub fn scan_addresses(
+        scan: &PyKeypair,
+        spend_public_key: &str,
+        input_public_keys: Vec<PyPublicKey>,
+        input_outpoints: Vec<PyTransactionOutpoint>,
+        count: u32,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network: PyNetworkType,
+    ) -> PyResult<Vec<PyAddress>> {
+        if input_public_keys.is_empty() || input_outpoints.is_empty() {
+            return Err(PyException::new_err(
+                "silent payments require at least one input public key and outpoint",
+            ));
+        }
+        if input_public_keys.len() != input_outpoints.len() {
+            return Err(PyException::new_err(
+                "input_public_keys and input_outpoints must have the same length",
+            ));
+        }
+
+        let a_sum_public = sum_public_keys(&input_public_keys)?;
+        let input_hash = compute_input_hash(&input_outpoints, &a_sum_public)?;
+
+        let scanned_point = a_sum_public
+            .mul_tweak(secp256k1::SECP256K1, &hash_to_scalar(input_hash)?)
+            .map_err(|err| PyException::new_err(format!("ECDH failed: {err}")))?;
+
+        let scan_secret = secp256k1::SecretKey::from_str(&scan.get_private_key())
+            .map_err(|err| PyException::new_err(format!("Invalid scan key: {err}")))?;
+        let ecdh = scanned_point
+            .mul_tweak(secp256k1::SECP256K1, &secp256k1::Scalar::from(scan_secret))
+            .map_err(|err| PyException::new_err(format!("ECDH failed: {err}")))?;
+
+        let spend_public_key = secp256k1::XOnlyPublicKey::from_str(spend_public_key)
+            .map_err(|err| PyException::new_err(format!("Invalid spend key: {err}")))?;
+
+        let network: NetworkType = network.into();
+        let mut addresses = Vec::with_capacity(count as usize);
+        for k in 0..count {
+            let destination = derive_destination_key(&ecdh, k, &spend_public_key)?;
+            addresses.push(Address::new(network.into(), Version::PubKey, &destination.serialize()).into());
+        }
+        Ok(addresses)
+    }
+}
+
+impl PySilentPaymentAddress {
+    /// The recipient's scan key as a full (non x-only) public key, for ECDH.
+    fn scan_public_key_full(&self) -> secp256k1::PublicKey {
+        self.scan_public_key.public_key(secp256k1::Parity::Even)
+    }
+}
+
+/// Sum a set of private keys modulo the curve order into `a_sum`, per the
+/// silent-payments input-key aggregation scheme.
+fn sum_secret_keys(keys: &[PyPrivateKey]) -> PyResult<[u8; 32]> {
+    let mut sum: Option<secp256k1::SecretKey> = None;
+    for key in keys {
+        let mut bytes = key.secret_bytes();
+        let secret = secp256k1::SecretKey::from_slice(&bytes)
+            .map_err(|err| PyException::new_err(format!("Invalid input private key: {err}")))?;
+        bytes.zeroize();
+        sum = Some(match sum {
+            None => secret,
+            Some(acc) => acc
+                .add_tweak(&secp256k1::Scalar::from(secret))
+                .map_err(|_| PyException::new_err("summed input private keys are zero"))?,
+        });
+    }
+    Ok(sum.expect("keys is non-empty, checked by caller").secret_bytes())
+}
+
+/// Sum a set of public keys into `A_sum`, the public counterpart of `a_sum`.
+fn sum_public_keys(keys: &[PyPublicKey]) -> PyResult<secp256k1::PublicKey> {
+    let mut sum: Option<secp256k1::PublicKey> = None;
+    for key in keys {
+        let public_key = key
+            .0
+            .public_key
+            .ok_or_else(|| PyException::new_err("silent payments require full (non x-only) input public keys"))?;
+        sum = Some(match sum {
+            None => public_key,
+            Some(acc) => acc
+                .combine(&public_key)
+                .map_err(|_| PyException::new_err("summed input public keys are the point at infinity"))?,
+        });
+    }
+    Ok(sum.expect("keys is non-empty, checked by caller"))
+}
+
+/// `H("inputs" || lowest_serialized_outpoint || A_sum)`, the domain-separated
+/// hash that ties a payment's shared secret to its specific set of inputs.
+fn compute_input_hash(
+    outpoints: &[PyTransactionOutpoint],
+    a_sum_public: &secp256k1::PublicKey,
+) -> PyResult<[u8; 32]> {
+    let mut serialized: Vec<[u8; 36]> = Vec::with_capacity(outpoints.len());
+    for outpoint in outpoints {
+        let mut bytes = [0u8; 36];
+        let txid = Vec::from_hex(&outpoint.get_transaction_id())
+            .map_err(|err| PyException::new_err(format!("Invalid outpoint transaction id: {err}")))?;
+        bytes[..32].copy_from_slice(&txid);
+        bytes[32..].copy_from_slice(&outpoint.get_index().to_le_bytes());
+        serialized.push(bytes);
+    }
+    serialized.sort();
+    let lowest_outpoint = serialized[0];
+
+    let mut hasher = Sha256::new();
+    hasher.update(b"inputs");
+    hasher.update(lowest_outpoint);
+    hasher.update(a_sum_public.serialize());
+    Ok(hasher.finalize().into())
+}
+
+/// Interpret a 32-byte hash as a secp256k1 scalar tweak.
+fn hash_to_scalar(hash: [u8; 32]) -> PyResult<secp256k1::Scalar> {
+    secp256k1::Scalar::from_be_bytes(hash)
+        .map_err(|_| PyException::new_err("derived hash is not a valid scalar"))
+}
+
+/// `a_sum_key * input_hash`, as the scalar tweak to apply to `B_scan`.
+fn scalar_product(input_hash: &[u8; 32], a_sum_key: &secp256k1::SecretKey) -> PyResult<secp256k1::Scalar> {
+    let product = a_sum_key
+        .mul_tweak(&hash_to_scalar(*input_hash)?)
+        .map_err(|err| PyException::new_err(format!("scalar multiplication failed: {err}")))?;
+    Ok(secp256k1::Scalar::from(product))
+}
+
+/// `t_k = H("SharedSecret" || ecdh || ser32(k))` and `P_k = B_spend + t_k·G`.
+fn derive_destination_key(
+    ecdh: &secp256k1::PublicKey,
+    k: u32,
+    spend_public_key: &secp256k1::XOnlyPublicKey,
+) -> PyResult<secp256k1::XOnlyPublicKey> {
+    let mut hasher = Sha256::new();
+    hasher.update(b"SharedSecret");
+    hasher.update(ecdh.serialize());
+    hasher.update(k.to_be_bytes());
+    let t_k: [u8; 32] = hasher.finalize().into();
+
+    let destination = spend_public_key
+        .public_key(secp256k1::Parity::Even)
+        .add_exp_tweak(secp256k1::SECP256K1, &hash_to_scalar(t_k)?)
+        .map_err(|err| PyException::new_err(format!("output key derivation failed: {err}")))?;
+    let (xonly, _) = destination.x_only_public_key();
+    Ok(xonly)
+}
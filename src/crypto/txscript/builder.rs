@@ -8,6 +8,23 @@ use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 use std::sync::{Arc, Mutex, MutexGuard};
 use workflow_core::hex::ToHex;
 
+/// `OP_IF`.
+const OP_IF: u8 = 0x63;
+/// `OP_ELSE`.
+const OP_ELSE: u8 = 0x67;
+/// `OP_ENDIF`.
+const OP_ENDIF: u8 = 0x68;
+/// `OP_DROP`.
+const OP_DROP: u8 = 0x75;
+/// `OP_EQUALVERIFY`.
+const OP_EQUALVERIFY: u8 = 0x88;
+/// `OP_SHA256`.
+const OP_SHA256: u8 = 0xa8;
+/// `OP_CHECKSIG`.
+const OP_CHECKSIG: u8 = 0xac;
+/// `OP_CHECKLOCKTIMEVERIFY`.
+const OP_CHECKLOCKTIMEVERIFY: u8 = 0xb1;
+
 /// Builder for constructing transaction scripts.
 ///
 /// Provides a fluent interface for building custom scripts with opcodes and data.
@@ -247,6 +264,253 @@ impl PyScriptBuilder {
         Ok(generated_script.to_hex())
     }
 
+    /// Build a standard m-of-n multisig redeem script.
+    ///
+    /// Delegates to the native `multisig_redeem_script`, the same canonical
+    /// builder `create_address`/`create_multisig_address_with_script` and
+    /// `MultisigAccount` use, rather than hand-assembling `OP_CHECKMULTISIG`
+    /// a second, potentially divergent way.
+    ///
+    /// Args:
+    ///     required: The number of signatures required (`m`).
+    ///     pubkeys: The 32-byte Schnorr public keys (`n` of them).
+    ///
+    /// Returns:
+    ///     ScriptBuilder: A new ScriptBuilder holding the multisig redeem script.
+    ///
+    /// Raises:
+    ///     Exception: If `required` or the number of public keys is outside
+    ///     `1..=16`, `required` exceeds the number of public keys, or a
+    ///     public key is not 32 bytes.
+    #[staticmethod]
+    pub fn multisig(required: u8, pubkeys: Vec<PyBinary>) -> PyResult<Self> {
+        let n = pubkeys.len();
+        if n == 0 || n > 16 {
+            return Err(PyException::new_err(
+                "multisig requires between 1 and 16 public keys",
+            ));
+        }
+        if required < 1 || required as usize > n {
+            return Err(PyException::new_err(format!(
+                "multisig `required` ({required}) must be between 1 and the number of public keys ({n})"
+            )));
+        }
+        let pubkeys = pubkeys
+            .iter()
+            .map(|pubkey| {
+                <[u8; 32]>::try_from(pubkey.as_ref()).map_err(|_| {
+                    PyException::new_err(format!(
+                        "multisig public keys must be 32 bytes, got {}",
+                        pubkey.data.len()
+                    ))
+                })
+            })
+            .collect::<PyResult<Vec<[u8; 32]>>>()?;
+
+        let redeem_script = standard::multisig_redeem_script(pubkeys.iter(), required as usize)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+
+        PyScriptBuilder::from_script(PyBinary { data: redeem_script.as_ref().to_vec() })
+    }
+
+    /// Encode a P2SH multisig unlocking (signature) script.
+    ///
+    /// Pushes each signature, in the same order as their corresponding
+    /// public keys in the redeem script, followed by this builder's script
+    /// itself - the standard P2SH multisig scriptSig.
+    ///
+    /// Args:
+    ///     signatures: The signatures to push, in order.
+    ///
+    /// Returns:
+    ///     str: The encoded signature script as hex.
+    ///
+    /// Raises:
+    ///     Exception: If encoding fails.
+    pub fn encode_multisig_signature_script(&self, signatures: Vec<PyBinary>) -> PyResult<String> {
+        let inner = self.inner();
+        let script = inner.script();
+
+        let mut sig_builder = native::ScriptBuilder::new();
+        for signature in &signatures {
+            sig_builder
+                .add_data(signature.as_ref())
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+        }
+        sig_builder
+            .add_data(script)
+            .map_err(|err| PyException::new_err(format!("{}", err)))?;
+
+        Ok(sig_builder.script().to_hex())
+    }
+
+    /// Build a Hash-Time-Locked Contract (HTLC) redeem script for atomic swaps.
+    ///
+    /// The script is `OP_IF OP_SHA256 <hash_lock> OP_EQUALVERIFY
+    /// <recipient_pubkey> OP_CHECKSIG OP_ELSE <lock_time>
+    /// OP_CHECKLOCKTIMEVERIFY OP_DROP <refund_pubkey> OP_CHECKSIG OP_ENDIF`:
+    /// the recipient can claim with the `hash_lock` preimage at any time,
+    /// while the refund path only unlocks for `refund_pubkey` after `lock_time`.
+    /// Wrap the result with `create_pay_to_script_hash_script` to get the
+    /// P2SH locking script.
+    ///
+    /// Args:
+    ///     hash_lock: The 32-byte SHA256 hash of the claim preimage.
+    ///     recipient_pubkey: The 32-byte Schnorr public key that can claim.
+    ///     refund_pubkey: The 32-byte Schnorr public key that can refund.
+    ///     lock_time: The DAA score after which the refund path unlocks.
+    ///
+    /// Returns:
+    ///     ScriptBuilder: A new ScriptBuilder holding the HTLC redeem script.
+    ///
+    /// Raises:
+    ///     Exception: If `hash_lock` is not 32 bytes, or either public key is
+    ///     not a 32-byte Schnorr public key.
+    #[staticmethod]
+    pub fn htlc(
+        hash_lock: PyBinary,
+        recipient_pubkey: PyBinary,
+        refund_pubkey: PyBinary,
+        lock_time: u64,
+    ) -> PyResult<Self> {
+        if hash_lock.data.len() != 32 {
+            return Err(PyException::new_err(format!(
+                "HTLC hash_lock must be a 32-byte SHA256 digest, got {}",
+                hash_lock.data.len()
+            )));
+        }
+        if recipient_pubkey.data.len() != 32 {
+            return Err(PyException::new_err(format!(
+                "HTLC recipient_pubkey must be a 32-byte Schnorr public key, got {}",
+                recipient_pubkey.data.len()
+            )));
+        }
+        if refund_pubkey.data.len() != 32 {
+            return Err(PyException::new_err(format!(
+                "HTLC refund_pubkey must be a 32-byte Schnorr public key, got {}",
+                refund_pubkey.data.len()
+            )));
+        }
+
+        let builder = PyScriptBuilder::default();
+        {
+            let mut inner = builder.inner();
+            inner
+                .add_op(OP_IF)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_op(OP_SHA256)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_data(hash_lock.as_ref())
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_op(OP_EQUALVERIFY)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_data(recipient_pubkey.as_ref())
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_op(OP_CHECKSIG)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_op(OP_ELSE)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_lock_time(lock_time)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_op(OP_CHECKLOCKTIMEVERIFY)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_op(OP_DROP)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_data(refund_pubkey.as_ref())
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_op(OP_CHECKSIG)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            inner
+                .add_op(OP_ENDIF)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+        }
+
+        Ok(builder)
+    }
+
+    /// Encode the HTLC claim-path unlocking script.
+    ///
+    /// Pushes `signature`, the `preimage` that hashes to `hash_lock`, the
+    /// `OP_IF` branch selector, and this builder's script (the HTLC redeem
+    /// script) itself.
+    ///
+    /// Args:
+    ///     signature: The recipient's signature.
+    ///     preimage: The preimage whose SHA256 hash equals `hash_lock`.
+    ///
+    /// Returns:
+    ///     str: The encoded signature script as hex.
+    ///
+    /// Raises:
+    ///     Exception: If encoding fails.
+    pub fn encode_htlc_claim_script(
+        &self,
+        signature: PyBinary,
+        preimage: PyBinary,
+    ) -> PyResult<String> {
+        let inner = self.inner();
+        let script = inner.script();
+
+        let mut sig_builder = native::ScriptBuilder::new();
+        sig_builder
+            .add_data(signature.as_ref())
+            .map_err(|err| PyException::new_err(format!("{}", err)))?;
+        sig_builder
+            .add_data(preimage.as_ref())
+            .map_err(|err| PyException::new_err(format!("{}", err)))?;
+        sig_builder
+            .add_i64(1)
+            .map_err(|err| PyException::new_err(format!("{}", err)))?;
+        sig_builder
+            .add_data(script)
+            .map_err(|err| PyException::new_err(format!("{}", err)))?;
+
+        Ok(sig_builder.script().to_hex())
+    }
+
+    /// Encode the HTLC refund-path unlocking script.
+    ///
+    /// Pushes `signature`, the `OP_ELSE` branch selector, and this builder's
+    /// script (the HTLC redeem script) itself. Only valid once `lock_time`
+    /// has passed.
+    ///
+    /// Args:
+    ///     signature: The refund party's signature.
+    ///
+    /// Returns:
+    ///     str: The encoded signature script as hex.
+    ///
+    /// Raises:
+    ///     Exception: If encoding fails.
+    pub fn encode_htlc_refund_script(&self, signature: PyBinary) -> PyResult<String> {
+        let inner = self.inner();
+        let script = inner.script();
+
+        let mut sig_builder = native::ScriptBuilder::new();
+        sig_builder
+            .add_data(signature.as_ref())
+            .map_err(|err| PyException::new_err(format!("{}", err)))?;
+        sig_builder
+            .add_i64(0)
+            .map_err(|err| PyException::new_err(format!("{}", err)))?;
+        sig_builder
+            .add_data(script)
+            .map_err(|err| PyException::new_err(format!("{}", err)))?;
+
+        Ok(sig_builder.script().to_hex())
+    }
+
     // Cannot be derived via pyclass(eq)
     fn __eq__(&self, other: &PyScriptBuilder) -> bool {
         match (
@@ -257,6 +521,41 @@ impl PyScriptBuilder {
             _ => false,
         }
     }
+
+    /// Decode the script into `(opcode_name, data_hex)` tuples.
+    ///
+    /// Walks the script buffer, decoding `OP_DATA_1..OP_DATA_75` pushes and
+    /// `OP_PUSHDATA1/2/4` length-prefixed pushes alongside plain opcodes. A
+    /// push whose declared length runs past the end of the script is
+    /// reported as a final `("OP_INVALID", <remaining bytes as hex>)` entry
+    /// and ends the walk early.
+    ///
+    /// Returns:
+    ///     list[tuple[str, str | None]]: One entry per decoded opcode, with
+    ///     the pushed data as a hex string where applicable.
+    pub fn disassemble(&self) -> Vec<(String, Option<String>)> {
+        let inner = self.inner();
+        let script = inner.script();
+        disassemble_script(script)
+    }
+
+    /// Render the script as a human-readable ASM string.
+    ///
+    /// Equivalent to joining `disassemble()` with spaces, e.g.
+    /// `"OP_DUP OP_HASH160 89ab... OP_EQUALVERIFY OP_CHECKSIG"`.
+    ///
+    /// Returns:
+    ///     str: The script in ASM form.
+    pub fn to_asm(&self) -> String {
+        disassemble_script(self.inner().script())
+            .into_iter()
+            .map(|(name, data)| match data {
+                Some(hex) => format!("{name} {hex}"),
+                None => name,
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
 }
 
 // TODO change to PyOpcode struct and handle similar to PyBinary?
@@ -285,3 +584,161 @@ fn extract_op(item: &Bound<PyAny>) -> PyResult<u8> {
         Err(PyException::new_err("Expected Opcodes enum variant or u8"))
     }
 }
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Walk a raw script buffer, decoding each opcode and any data it pushes.
+fn disassemble_script(script: &[u8]) -> Vec<(String, Option<String>)> {
+    let mut result = Vec::new();
+    let mut i = 0usize;
+
+    while i < script.len() {
+        let op = script[i];
+        i += 1;
+
+        let push_len = match op {
+            0x01..=0x4b => Some((format!("OP_DATA_{op}"), op as usize)),
+            0x4c => {
+                if i >= script.len() {
+                    result.push(("OP_INVALID".to_string(), Some(hex_encode(&script[i..]))));
+                    break;
+                }
+                let len = script[i] as usize;
+                i += 1;
+                Some(("OP_PUSHDATA1".to_string(), len))
+            }
+            0x4d => {
+                if i + 2 > script.len() {
+                    result.push(("OP_INVALID".to_string(), Some(hex_encode(&script[i..]))));
+                    break;
+                }
+                let len = u16::from_le_bytes([script[i], script[i + 1]]) as usize;
+                i += 2;
+                Some(("OP_PUSHDATA2".to_string(), len))
+            }
+            0x4e => {
+                if i + 4 > script.len() {
+                    result.push(("OP_INVALID".to_string(), Some(hex_encode(&script[i..]))));
+                    break;
+                }
+                let len = u32::from_le_bytes([script[i], script[i + 1], script[i + 2], script[i + 3]]) as usize;
+                i += 4;
+                Some(("OP_PUSHDATA4".to_string(), len))
+            }
+            _ => None,
+        };
+
+        match push_len {
+            Some((name, len)) => {
+                if i + len > script.len() {
+                    result.push(("OP_INVALID".to_string(), Some(hex_encode(&script[i..]))));
+                    break;
+                }
+                result.push((name, Some(hex_encode(&script[i..i + len]))));
+                i += len;
+            }
+            None => {
+                let name = opcode_name(op)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("OP_UNKNOWN_0x{op:02x}"));
+                result.push((name, None));
+            }
+        }
+    }
+
+    result
+}
+
+/// Human-readable name for a script opcode that carries no operand, or
+/// `None` if the opcode isn't recognized.
+fn opcode_name(op: u8) -> Option<&'static str> {
+    Some(match op {
+        0x00 => "OP_0",
+        0x4f => "OP_1NEGATE",
+        0x50 => "OP_RESERVED",
+        0x51 => "OP_1",
+        0x52 => "OP_2",
+        0x53 => "OP_3",
+        0x54 => "OP_4",
+        0x55 => "OP_5",
+        0x56 => "OP_6",
+        0x57 => "OP_7",
+        0x58 => "OP_8",
+        0x59 => "OP_9",
+        0x5a => "OP_10",
+        0x5b => "OP_11",
+        0x5c => "OP_12",
+        0x5d => "OP_13",
+        0x5e => "OP_14",
+        0x5f => "OP_15",
+        0x60 => "OP_16",
+        0x61 => "OP_NOP",
+        0x62 => "OP_VER",
+        0x63 => "OP_IF",
+        0x64 => "OP_NOTIF",
+        0x65 => "OP_VERIF",
+        0x66 => "OP_VERNOTIF",
+        0x67 => "OP_ELSE",
+        0x68 => "OP_ENDIF",
+        0x69 => "OP_VERIFY",
+        0x6a => "OP_RETURN",
+        0x6b => "OP_TOALTSTACK",
+        0x6c => "OP_FROMALTSTACK",
+        0x6d => "OP_2DROP",
+        0x6e => "OP_2DUP",
+        0x6f => "OP_3DUP",
+        0x70 => "OP_2OVER",
+        0x71 => "OP_2ROT",
+        0x72 => "OP_2SWAP",
+        0x73 => "OP_IFDUP",
+        0x74 => "OP_DEPTH",
+        0x75 => "OP_DROP",
+        0x76 => "OP_DUP",
+        0x77 => "OP_NIP",
+        0x78 => "OP_OVER",
+        0x79 => "OP_PICK",
+        0x7a => "OP_ROLL",
+        0x7b => "OP_ROT",
+        0x7c => "OP_SWAP",
+        0x7d => "OP_TUCK",
+        0x82 => "OP_SIZE",
+        0x87 => "OP_EQUAL",
+        0x88 => "OP_EQUALVERIFY",
+        0x8b => "OP_1ADD",
+        0x8c => "OP_1SUB",
+        0x8f => "OP_NEGATE",
+        0x90 => "OP_ABS",
+        0x91 => "OP_NOT",
+        0x92 => "OP_0NOTEQUAL",
+        0x93 => "OP_ADD",
+        0x94 => "OP_SUB",
+        0x9a => "OP_BOOLAND",
+        0x9b => "OP_BOOLOR",
+        0x9c => "OP_NUMEQUAL",
+        0x9d => "OP_NUMEQUALVERIFY",
+        0x9e => "OP_NUMNOTEQUAL",
+        0x9f => "OP_LESSTHAN",
+        0xa0 => "OP_GREATERTHAN",
+        0xa1 => "OP_LESSTHANOREQUAL",
+        0xa2 => "OP_GREATERTHANOREQUAL",
+        0xa3 => "OP_MIN",
+        0xa4 => "OP_MAX",
+        0xa5 => "OP_WITHIN",
+        0xa6 => "OP_RIPEMD160",
+        0xa7 => "OP_SHA1",
+        0xa8 => "OP_SHA256",
+        0xa9 => "OP_HASH160",
+        0xaa => "OP_HASH256",
+        0xab => "OP_CODESEPARATOR",
+        0xac => "OP_CHECKSIG",
+        0xad => "OP_CHECKSIGVERIFY",
+        0xae => "OP_CHECKMULTISIG",
+        0xaf => "OP_CHECKMULTISIGVERIFY",
+        0xb0 => "OP_NOP1",
+        0xb1 => "OP_CHECKLOCKTIMEVERIFY",
+        0xb2 => "OP_CHECKSEQUENCEVERIFY",
+        _ => return None,
+    })
+}
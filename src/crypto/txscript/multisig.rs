@@ -0,0 +1,72 @@
+use crate::{address::PyAddress, consensus::core::network::PyNetworkType, types::PyBinary};
+use kaspa_consensus_core::network::NetworkType;
+use kaspa_txscript::standard::{
+    extract_script_pub_key_address, multisig_redeem_script, pay_to_script_hash_script,
+};
+use pyo3::{exceptions::PyException, prelude::*};
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+
+/// Build a standard Kaspa m-of-n multisig redeem script from raw public keys.
+///
+/// Args:
+///     public_keys: The cosigners' x-only (32-byte) schnorr public keys.
+///     minimum_signatures: The minimum number of signatures required to spend.
+///
+/// Returns:
+///     bytes: The serialized redeem script.
+///
+/// Raises:
+///     Exception: If `minimum_signatures` is zero or exceeds the number of keys.
+///
+/// Category: Wallet/Transactions
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "create_multisig_script")]
+pub fn py_create_multisig_script(
+    public_keys: Vec<PyBinary>,
+    minimum_signatures: usize,
+) -> PyResult<PyBinary> {
+    let pub_keys: Vec<[u8; 32]> = public_keys
+        .into_iter()
+        .map(|key| {
+            key.data.as_slice().try_into().map_err(|_| {
+                PyException::new_err("each public key must be exactly 32 bytes (x-only)")
+            })
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let script = multisig_redeem_script(pub_keys.iter(), minimum_signatures)
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    Ok(PyBinary { data: script })
+}
+
+/// Derive the pay-to-script-hash multisig address for a set of public keys.
+///
+/// Args:
+///     network_type: The network type for address encoding.
+///     public_keys: The cosigners' x-only (32-byte) schnorr public keys.
+///     minimum_signatures: The minimum number of signatures required to spend.
+///
+/// Returns:
+///     Address: The P2SH address that locks funds to this multisig script.
+///
+/// Raises:
+///     Exception: If script construction or address derivation fails.
+///
+/// Category: Wallet/Transactions
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "multisig_address")]
+pub fn py_multisig_address(
+    #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
+    public_keys: Vec<PyBinary>,
+    minimum_signatures: usize,
+) -> PyResult<PyAddress> {
+    let script = py_create_multisig_script(public_keys, minimum_signatures)?;
+    let script_public_key = pay_to_script_hash_script(&script.data);
+    let network_type: NetworkType = network_type.into();
+    let address = extract_script_pub_key_address(&script_public_key, network_type.into())
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    Ok(address.into())
+}
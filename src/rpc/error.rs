@@ -0,0 +1,62 @@
+use kaspa_rpc_core::RpcError;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+
+pub struct PyRpcError(pub RpcError);
+
+/// Common base for every structured RPC exception below.
+///
+/// Lets Python callers write `except KaspaRpcError` to catch any of the
+/// specific `Rpc*Error` subclasses without string-matching messages, the
+/// same way `KaspaError` does for the PSKT error surface.
+create_exception!("kaspa.exceptions", KaspaRpcError, PyException);
+
+/// Declares a `KaspaRpcError` subclass for one `kaspa_rpc_core` error
+/// variant, with a stable, machine-readable `code` string attached as the
+/// second element of `err.args` (`err.args == (message, code)`).
+macro_rules! kaspa_rpc_error {
+    ($name:ident, $code:literal) => {
+        create_exception!("kaspa.exceptions", $name, KaspaRpcError);
+
+        impl $name {
+            pub const CODE: &'static str = $code;
+
+            pub fn new_err(message: impl Into<String>) -> PyErr {
+                PyErr::new::<Self, _>((message.into(), $code))
+            }
+        }
+    };
+}
+
+kaspa_rpc_error!(RpcNotConnectedError, "rpc.not_connected");
+kaspa_rpc_error!(RpcTimeoutError, "rpc.timeout");
+kaspa_rpc_error!(RpcNotSyncedError, "rpc.not_synced");
+kaspa_rpc_error!(RpcMethodNotFoundError, "rpc.method_not_found");
+kaspa_rpc_error!(RpcInvalidParamsError, "rpc.invalid_params");
+kaspa_rpc_error!(RpcSubscriptionError, "rpc.subscription");
+kaspa_rpc_error!(RpcDisconnectedError, "rpc.disconnected");
+kaspa_rpc_error!(RpcGenericError, "rpc.generic");
+
+impl From<PyRpcError> for PyErr {
+    fn from(value: PyRpcError) -> Self {
+        match value.0 {
+            RpcError::NotConnected => RpcNotConnectedError::new_err("RPC client is not connected"),
+            RpcError::Timeout(msg) => RpcTimeoutError::new_err(msg),
+            RpcError::NotSynced => RpcNotSyncedError::new_err("Node is not synced"),
+            RpcError::MethodNotFound(method) => {
+                RpcMethodNotFoundError::new_err(format!("Unknown RPC method: {method}"))
+            }
+            RpcError::InvalidParameter(msg) => RpcInvalidParamsError::new_err(msg),
+            RpcError::SubscriptionLimitExceeded(msg) => RpcSubscriptionError::new_err(msg),
+            RpcError::Disconnected => RpcDisconnectedError::new_err("RPC connection was closed"),
+            other => RpcGenericError::new_err(other.to_string()),
+        }
+    }
+}
+
+impl From<RpcError> for PyRpcError {
+    fn from(value: RpcError) -> Self {
+        PyRpcError(value)
+    }
+}
@@ -2,6 +2,7 @@ use crate::address::PyAddress;
 use crate::callback::PyCallback;
 use crate::consensus::core::network::{PyNetworkId, PyNetworkType};
 use crate::rpc::encoding::PyEncoding;
+use crate::rpc::error::PyRpcError;
 use crate::rpc::model::*;
 use crate::rpc::notification::PyNotification;
 use crate::rpc::wrpc::resolver::PyResolver;
@@ -23,9 +24,9 @@ use kaspa_wrpc_client::{
 };
 use paste::paste;
 use pyo3::{
-    exceptions::PyException,
+    exceptions::{PyException, PyStopAsyncIteration},
     prelude::*,
-    types::{PyDict, PyTuple},
+    types::{PyDict, PyList, PyTuple},
 };
 use pyo3_stub_gen::derive::*;
 use serde::{Deserialize, Serialize};
@@ -33,11 +34,11 @@ use std::str::FromStr;
 use std::{
     sync::{
         Arc, Mutex,
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicUsize, Ordering},
     },
     time::Duration,
 };
-use workflow_core::channel::{Channel, DuplexChannel};
+use workflow_core::channel::{Channel, DuplexChannel, Receiver, Sender};
 use workflow_log::*;
 use workflow_rpc::{client::Ctl, encoding::Encoding};
 
@@ -59,6 +60,8 @@ use workflow_rpc::{client::Ctl, encoding::Encoding};
 ///     - NewBlockTemplate: Triggered when a new block template is available for mining.
 ///     - Connect: Triggered when the RPC client connects to a node.
 ///     - Disconnect: Triggered when the RPC client disconnects from a node.
+///     - Lagged: Triggered when the bounded notification buffer overflows and a notification is dropped.
+///     - Error: Triggered when a registered callback raises an exception, instead of crashing the notification task.
 #[gen_stub_pyclass_enum]
 #[pyclass(name = "NotificationEvent", skip_from_py_object, eq)]
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -80,6 +83,10 @@ pub enum PyNotificationEvent {
     // RPC Control
     Connect,
     Disconnect,
+
+    // Buffer Diagnostics
+    Lagged,
+    Error,
 }
 
 impl<'py> FromPyObject<'_, 'py> for PyNotificationEvent {
@@ -104,6 +111,12 @@ enum NotificationEvent {
     All,
     Notification(EventType),
     RpcCtl(Ctl),
+    /// Emitted when the bounded notification buffer overflows and a
+    /// notification is dropped under `NotificationOverflowPolicy`.
+    Lagged,
+    /// Emitted when a registered callback raises an exception, instead of
+    /// crashing the notification task.
+    Error,
 }
 
 impl FromStr for NotificationEvent {
@@ -111,6 +124,10 @@ impl FromStr for NotificationEvent {
     fn from_str(s: &str) -> Result<Self> {
         if s == "all" {
             Ok(NotificationEvent::All)
+        } else if s == "lagged" {
+            Ok(NotificationEvent::Lagged)
+        } else if s == "error" {
+            Ok(NotificationEvent::Error)
         } else if let Ok(ctl) = Ctl::from_str(s) {
             Ok(NotificationEvent::RpcCtl(ctl))
         } else if let Ok(event) = EventType::from_str(s) {
@@ -161,6 +178,97 @@ impl From<PyNotificationEvent> for NotificationEvent {
             // RPC Control
             PyNotificationEvent::Connect => NotificationEvent::RpcCtl(Ctl::Connect),
             PyNotificationEvent::Disconnect => NotificationEvent::RpcCtl(Ctl::Disconnect),
+
+            // Buffer Diagnostics
+            PyNotificationEvent::Lagged => NotificationEvent::Lagged,
+            PyNotificationEvent::Error => NotificationEvent::Error,
+        }
+    }
+}
+
+/// The default capacity of the bounded notification buffer, used when
+/// `notification_capacity` is not supplied at client construction.
+const DEFAULT_NOTIFICATION_CAPACITY: usize = 256;
+
+/// Default ceiling on concurrent active subscriptions per [`PyRpcClient`].
+const DEFAULT_MAX_SUBSCRIPTIONS: usize = 1024;
+
+/// Policy applied once the bounded notification buffer reaches capacity.
+///
+/// Mirrors the overflow strategies used in the openethereum IO refactor and
+/// netapp message framing: a slow Python consumer should never let node
+/// notifications buffer without limit, but different applications disagree
+/// on whether correctness (never miss a notification) or liveness (never
+/// stall) matters more, so the policy is selectable at construction.
+///
+/// Variants:
+///     - Block: Apply backpressure, pausing delivery of new notifications until the consumer drains the buffer.
+///     - DropOldest: Discard the oldest buffered notification to make room for the incoming one.
+///     - DropNewest: Discard the incoming notification, leaving the existing buffer untouched.
+#[gen_stub_pyclass_enum]
+#[pyclass(name = "NotificationOverflowPolicy", skip_from_py_object, eq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PyNotificationOverflowPolicy {
+    Block,
+    DropOldest,
+    DropNewest,
+}
+
+impl FromStr for PyNotificationOverflowPolicy {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> PyResult<Self> {
+        match s.to_lowercase().replace(['-', '_'], "").as_str() {
+            "block" => Ok(Self::Block),
+            "dropoldest" => Ok(Self::DropOldest),
+            "dropnewest" => Ok(Self::DropNewest),
+            _ => Err(PyException::new_err(
+                "Unsupported string value for NotificationOverflowPolicy",
+            )),
+        }
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyNotificationOverflowPolicy {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(s) = obj.extract::<String>() {
+            PyNotificationOverflowPolicy::from_str(&s)
+        } else if let Ok(t) = obj.cast::<PyNotificationOverflowPolicy>() {
+            Ok(*t.borrow())
+        } else {
+            Err(PyException::new_err(
+                "Expected type `str` or `NotificationOverflowPolicy`",
+            ))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum NotificationOverflowPolicy {
+    #[default]
+    Block,
+    DropOldest,
+    DropNewest,
+}
+
+impl From<PyNotificationOverflowPolicy> for NotificationOverflowPolicy {
+    fn from(value: PyNotificationOverflowPolicy) -> Self {
+        match value {
+            PyNotificationOverflowPolicy::Block => NotificationOverflowPolicy::Block,
+            PyNotificationOverflowPolicy::DropOldest => NotificationOverflowPolicy::DropOldest,
+            PyNotificationOverflowPolicy::DropNewest => NotificationOverflowPolicy::DropNewest,
+        }
+    }
+}
+
+impl From<NotificationOverflowPolicy> for PyNotificationOverflowPolicy {
+    fn from(value: NotificationOverflowPolicy) -> Self {
+        match value {
+            NotificationOverflowPolicy::Block => PyNotificationOverflowPolicy::Block,
+            NotificationOverflowPolicy::DropOldest => PyNotificationOverflowPolicy::DropOldest,
+            NotificationOverflowPolicy::DropNewest => PyNotificationOverflowPolicy::DropNewest,
         }
     }
 }
@@ -171,8 +279,57 @@ pub struct Inner {
     notification_task: Arc<AtomicBool>,
     notification_ctl: DuplexChannel,
     callbacks: Arc<Mutex<AHashMap<NotificationEvent, Vec<PyCallback>>>>,
+    streams: Arc<Mutex<AHashMap<NotificationEvent, Vec<Sender<Py<PyDict>>>>>>,
     listener_id: Arc<Mutex<Option<ListenerId>>>,
+    /// Unbounded channel registered with the node's listener connection, so the
+    /// node's own notification dispatch is never blocked or made to drop messages.
+    raw_notification_channel: Channel<kaspa_rpc_core::Notification>,
+    /// Bounded staging channel the dispatch loop actually consumes from; the relay
+    /// between the two applies `notification_overflow_policy` once it is full.
     notification_channel: Channel<kaspa_rpc_core::Notification>,
+    notification_capacity: usize,
+    notification_overflow_policy: NotificationOverflowPolicy,
+    notification_queued: Arc<AtomicUsize>,
+    notification_dropped: Arc<AtomicUsize>,
+    notification_high_water_mark: Arc<AtomicUsize>,
+    keepalive_task: Arc<AtomicBool>,
+    keepalive_ctl: DuplexChannel,
+    /// Set while `shutdown()` is draining outstanding calls; new RPC submissions
+    /// are rejected while this is `true`.
+    draining: Arc<AtomicBool>,
+    /// Number of RPC calls currently awaiting a response, tracked by [`InFlightGuard`].
+    in_flight: Arc<AtomicUsize>,
+    shutdown_signal_task: Arc<AtomicBool>,
+    /// Every scope currently subscribed to, with a refcount so repeated
+    /// `subscribe_*` calls for the same scope are only dropped from the
+    /// registry once every matching `unsubscribe_*` has been observed.
+    subscriptions: Arc<Mutex<Vec<(Scope, usize)>>>,
+    /// When set, every stored subscription is replayed against the node as
+    /// soon as a fresh `listener_id` is obtained after a reconnect.
+    auto_resubscribe: bool,
+    /// Number of `subscribe_*` calls currently reserved against `max_subscriptions`.
+    subscription_count: Arc<AtomicUsize>,
+    /// Ceiling on concurrent active subscriptions, guarding against a leaking
+    /// client exhausting node-side notification resources.
+    max_subscriptions: usize,
+}
+
+/// RAII guard tracking one in-flight RPC call; held across the `.await` of the
+/// underlying call so `Inner::in_flight` always reflects outstanding requests,
+/// even if the call is cancelled or errors out.
+struct InFlightGuard(Arc<AtomicUsize>);
+
+impl InFlightGuard {
+    fn new(in_flight: Arc<AtomicUsize>) -> Self {
+        in_flight.fetch_add(1, Ordering::SeqCst);
+        Self(in_flight)
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 impl Inner {
@@ -190,6 +347,208 @@ impl Inner {
             (None, None) => None,
         }
     }
+
+    /// Collect the async-iterator stream senders registered for `event`, dropping
+    /// any whose receiving [`PyNotificationStream`] has already been closed/dropped.
+    fn notification_streams(&self, event: NotificationEvent) -> Vec<Sender<Py<PyDict>>> {
+        let mut streams = self.streams.lock().unwrap();
+        for senders in streams.values_mut() {
+            senders.retain(|sender| !sender.is_closed());
+        }
+        let mut result = Vec::new();
+        if let Some(senders) = streams.get(&NotificationEvent::All) {
+            result.extend(senders.iter().cloned());
+        }
+        if let Some(senders) = streams.get(&event) {
+            result.extend(senders.iter().cloned());
+        }
+        result
+    }
+
+    /// Invoke a registered callback with `event`. If the callback raises, the
+    /// exception is routed to any handler registered for the `"error"` event
+    /// (carrying the offending event and the exception text) instead of
+    /// propagating and crashing the notification task.
+    fn dispatch_callback(&self, py: Python, handler: &PyCallback, event_type: &str, event: Bound<'_, PyDict>) {
+        let original_event = event.copy().ok();
+        if let Err(err) = handler.execute(py, event) {
+            self.dispatch_callback_error(py, event_type, err, original_event);
+        }
+    }
+
+    /// Route a callback exception to every handler registered for the
+    /// `"error"` event. Swallows (and logs) any exception raised by an error
+    /// handler itself, so a broken error handler cannot cause a second crash.
+    fn dispatch_callback_error(
+        &self,
+        py: Python,
+        event_type: &str,
+        err: PyErr,
+        original_event: Option<Bound<'_, PyDict>>,
+    ) {
+        if let Some(handlers) = self.notification_callbacks(NotificationEvent::Error) {
+            for handler in handlers.into_iter() {
+                let error_event = PyDict::new(py);
+                error_event.set_item("type", "error").unwrap();
+                error_event.set_item("event_type", event_type).unwrap();
+                error_event.set_item("error", err.to_string()).unwrap();
+                if let Some(original_event) = &original_event {
+                    error_event.set_item("event", original_event).unwrap();
+                }
+                if let Err(err) = handler.execute(py, error_event) {
+                    log_error!("Error in \"error\" event handler: {}", err);
+                }
+            }
+        }
+    }
+
+    /// Notify registered callbacks/streams that a notification was dropped from
+    /// the bounded buffer, carrying the running drop total for the caller to track.
+    async fn dispatch_lagged(&self, dropped: usize) {
+        if let Some(handlers) = self.notification_callbacks(NotificationEvent::Lagged) {
+            for handler in handlers.into_iter() {
+                Python::attach(|py| {
+                    let event = PyDict::new(py);
+                    event.set_item("type", "lagged").unwrap();
+                    event.set_item("dropped", dropped).unwrap();
+                    self.dispatch_callback(py, &handler, "lagged", event);
+                });
+            }
+        }
+        let senders = self.notification_streams(NotificationEvent::Lagged);
+        for sender in senders {
+            let event = Python::attach(|py| -> Py<PyDict> {
+                let event = PyDict::new(py);
+                event.set_item("type", "lagged").unwrap();
+                event.set_item("dropped", dropped).unwrap();
+                event.unbind()
+            });
+            sender.send(event).await.ok();
+        }
+    }
+
+    /// Record a `start_notify` call in the subscription registry, so it can be
+    /// replayed after a reconnect when `auto_resubscribe` is enabled.
+    fn record_subscribe(&self, scope: Scope) {
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        match subscriptions.iter_mut().find(|(s, _)| *s == scope) {
+            Some((_, count)) => *count += 1,
+            None => subscriptions.push((scope, 1)),
+        }
+    }
+
+    /// Record a `stop_notify` call, dropping the scope from the registry once
+    /// its refcount reaches zero.
+    fn record_unsubscribe(&self, scope: &Scope) {
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        if let Some(index) = subscriptions.iter().position(|(s, _)| s == scope) {
+            subscriptions[index].1 = subscriptions[index].1.saturating_sub(1);
+            if subscriptions[index].1 == 0 {
+                subscriptions.remove(index);
+            }
+        }
+    }
+
+    /// Snapshot of every currently-subscribed scope, for replay after reconnect.
+    fn subscribed_scopes(&self) -> Vec<Scope> {
+        self.subscriptions
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(scope, _)| scope.clone())
+            .collect()
+    }
+
+    /// Reserve a slot against `max_subscriptions`, failing fast with a
+    /// dedicated error instead of letting a leaking caller exhaust node-side
+    /// notification resources. Call [`Inner::release_subscription`] if the
+    /// reserved subscription is not actually established (e.g. `start_notify` fails).
+    fn try_reserve_subscription(&self) -> PyResult<()> {
+        let mut count = self.subscription_count.load(Ordering::SeqCst);
+        loop {
+            if count >= self.max_subscriptions {
+                return Err(PyException::new_err(format!(
+                    "Subscription limit of {} reached: unsubscribe from unused scopes before subscribing further",
+                    self.max_subscriptions
+                )));
+            }
+            match self.subscription_count.compare_exchange_weak(
+                count,
+                count + 1,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => return Ok(()),
+                Err(observed) => count = observed,
+            }
+        }
+    }
+
+    /// Release a subscription slot reserved by [`Inner::try_reserve_subscription`].
+    fn release_subscription(&self) {
+        let _ = self.subscription_count.fetch_update(
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+            |count| Some(count.saturating_sub(1)),
+        );
+    }
+
+    /// Undo a partially-applied multi-scope `subscribe()`/`unsubscribe()` call:
+    /// for `subscribe`, `scopes` are the ones already established and need to be
+    /// stopped again; for `unsubscribe`, they are the ones already stopped and
+    /// need to be started again. Best-effort: a failure here is logged, not
+    /// propagated, since the caller is already unwinding from an earlier error.
+    async fn rollback_scopes(&self, client: &KaspaRpcClient, listener_id: ListenerId, scopes: Vec<Scope>, resubscribe: bool) {
+        for scope in scopes.into_iter().rev() {
+            let result = if resubscribe {
+                client.start_notify(listener_id, scope.clone()).await
+            } else {
+                client.stop_notify(listener_id, scope.clone()).await
+            };
+            match result {
+                Ok(()) => {
+                    if resubscribe {
+                        self.record_subscribe(scope);
+                    } else {
+                        self.record_unsubscribe(&scope);
+                        self.release_subscription();
+                    }
+                }
+                Err(err) => log_error!("Error rolling back subscription: {:?}", err),
+            }
+        }
+    }
+}
+
+/// An async iterator over RPC notification events, yielded as `dict` objects
+/// with the same shape passed to [`PyRpcClient::add_event_listener`] callbacks.
+///
+/// Returned by [`PyRpcClient::notifications`] for consumers that prefer
+/// `async for event in client.notifications(...)` over registering a callback.
+#[gen_stub_pyclass]
+#[pyclass(name = "NotificationStream")]
+pub struct PyNotificationStream {
+    receiver: Receiver<Py<PyDict>>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyNotificationStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let receiver = self.receiver.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            match receiver.recv().await {
+                Ok(event) => Ok(event),
+                Err(_) => Err(PyStopAsyncIteration::new_err(
+                    "notification stream has been closed",
+                )),
+            }
+        })
+    }
 }
 
 /// WebSocket RPC client for communicating with Kaspa nodes.
@@ -208,6 +567,10 @@ impl PyRpcClient {
         url: Option<String>,
         encoding: Option<PyEncoding>,
         network_id: Option<NetworkId>,
+        notification_capacity: Option<usize>,
+        notification_overflow_policy: Option<NotificationOverflowPolicy>,
+        auto_resubscribe: Option<bool>,
+        max_subscriptions: Option<usize>,
     ) -> PyResult<Self> {
         let encoding = encoding.unwrap_or(PyEncoding::Borsh);
         let url = url
@@ -231,14 +594,33 @@ impl PyRpcClient {
             .map_err(|err| PyException::new_err(err.to_string()))?,
         );
 
+        let notification_capacity = notification_capacity.unwrap_or(DEFAULT_NOTIFICATION_CAPACITY);
+        let notification_overflow_policy = notification_overflow_policy.unwrap_or_default();
+
         let rpc_client = PyRpcClient(Arc::new(Inner {
             client,
             resolver,
             notification_task: Arc::new(AtomicBool::new(false)),
             notification_ctl: DuplexChannel::oneshot(),
             callbacks: Arc::new(Default::default()),
+            streams: Arc::new(Default::default()),
             listener_id: Arc::new(Mutex::new(None)),
-            notification_channel: Channel::unbounded(),
+            raw_notification_channel: Channel::unbounded(),
+            notification_channel: Channel::bounded(notification_capacity),
+            notification_capacity,
+            notification_overflow_policy,
+            notification_queued: Arc::new(AtomicUsize::new(0)),
+            notification_dropped: Arc::new(AtomicUsize::new(0)),
+            notification_high_water_mark: Arc::new(AtomicUsize::new(0)),
+            keepalive_task: Arc::new(AtomicBool::new(false)),
+            keepalive_ctl: DuplexChannel::oneshot(),
+            draining: Arc::new(AtomicBool::new(false)),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            shutdown_signal_task: Arc::new(AtomicBool::new(false)),
+            subscriptions: Arc::new(Mutex::new(Vec::new())),
+            auto_resubscribe: auto_resubscribe.unwrap_or(false),
+            subscription_count: Arc::new(AtomicUsize::new(0)),
+            max_subscriptions: max_subscriptions.unwrap_or(DEFAULT_MAX_SUBSCRIPTIONS),
         }));
 
         Ok(rpc_client)
@@ -255,6 +637,10 @@ impl PyRpcClient {
     ///     url: Optional direct node URL.
     ///     encoding: RPC encoding - either a string ("borsh" or "json") or an Encoding enum variant (default: "borsh").
     ///     network_id: Network identifier (default: "mainnet").
+    ///     notification_capacity: Maximum number of notifications buffered awaiting dispatch before `notification_overflow_policy` applies (default: 256).
+    ///     notification_overflow_policy: Policy applied once the notification buffer is full - a string ("block", "drop_oldest", "drop_newest") or a NotificationOverflowPolicy enum variant (default: "block").
+    ///     auto_resubscribe: Automatically replay every active subscription against the node once a fresh listener is obtained after a reconnect (default: False).
+    ///     max_subscriptions: Ceiling on concurrent active subscriptions before `subscribe_*` calls fail fast (default: 1024).
     ///
     /// Returns:
     ///     RpcClient: A new RpcClient instance.
@@ -262,13 +648,20 @@ impl PyRpcClient {
     /// Raises:
     ///     Exception: If client creation fails.
     #[new]
-    #[pyo3(signature = (resolver=None, url=None, encoding=None, network_id=None))]
+    #[pyo3(signature = (resolver=None, url=None, encoding=None, network_id=None, notification_capacity=None, notification_overflow_policy=None, auto_resubscribe=None, max_subscriptions=None))]
     fn ctor(
         resolver: Option<PyResolver>,
         url: Option<String>,
         #[gen_stub(override_type(type_repr = "str | Encoding | None = Encoding.Borsh"))]
         encoding: Option<PyEncoding>,
         network_id: Option<PyNetworkId>,
+        notification_capacity: Option<usize>,
+        #[gen_stub(override_type(
+            type_repr = "str | NotificationOverflowPolicy | None = NotificationOverflowPolicy.Block"
+        ))]
+        notification_overflow_policy: Option<PyNotificationOverflowPolicy>,
+        auto_resubscribe: Option<bool>,
+        max_subscriptions: Option<usize>,
     ) -> PyResult<PyRpcClient> {
         let network_id = match network_id {
             Some(id) => id,
@@ -280,9 +673,31 @@ impl PyRpcClient {
             url,
             Some(encoding.unwrap_or(PyEncoding::Borsh)),
             Some(network_id.into()),
+            notification_capacity,
+            Some(notification_overflow_policy.unwrap_or(PyNotificationOverflowPolicy::Block).into()),
+            auto_resubscribe,
+            max_subscriptions,
         )
     }
 
+    /// Whether active subscriptions are automatically replayed after a reconnect.
+    #[getter]
+    fn get_auto_resubscribe(&self) -> bool {
+        self.0.auto_resubscribe
+    }
+
+    /// The configured ceiling on concurrent active subscriptions.
+    #[getter]
+    fn get_max_subscriptions(&self) -> usize {
+        self.0.max_subscriptions
+    }
+
+    /// The number of subscriptions currently reserved against `max_subscriptions`.
+    #[getter]
+    fn get_subscription_count(&self) -> usize {
+        self.0.subscription_count.load(Ordering::SeqCst)
+    }
+
     /// The current WebSocket connection URL, or None if not connected.
     #[getter]
     fn get_url(&self) -> Option<String> {
@@ -343,6 +758,63 @@ impl PyRpcClient {
         self.0.client.node_descriptor().map(|node| node.uid.clone())
     }
 
+    /// The configured capacity of the bounded notification buffer.
+    #[getter]
+    fn get_notification_capacity(&self) -> usize {
+        self.0.notification_capacity
+    }
+
+    /// The overflow policy applied once the notification buffer is full.
+    #[getter]
+    fn get_notification_overflow_policy(&self) -> PyNotificationOverflowPolicy {
+        self.0.notification_overflow_policy.into()
+    }
+
+    /// The number of notifications currently buffered awaiting dispatch.
+    #[getter]
+    fn get_notification_queued(&self) -> usize {
+        self.0.notification_queued.load(Ordering::SeqCst)
+    }
+
+    /// The total number of notifications dropped due to buffer overflow since this client was created.
+    #[getter]
+    fn get_notification_dropped(&self) -> usize {
+        self.0.notification_dropped.load(Ordering::SeqCst)
+    }
+
+    /// The highest number of notifications ever buffered at once since this client was created.
+    #[getter]
+    fn get_notification_high_water_mark(&self) -> usize {
+        self.0.notification_high_water_mark.load(Ordering::SeqCst)
+    }
+
+    /// Snapshot of the bounded notification buffer's diagnostics.
+    ///
+    /// Returns:
+    ///     dict: A dict with `capacity`, `overflow_policy`, `queued`, `dropped`
+    ///     and `high_water_mark` keys.
+    fn notification_stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let stats = PyDict::new(py);
+        stats.set_item("capacity", self.0.notification_capacity)?;
+        stats.set_item(
+            "overflow_policy",
+            PyNotificationOverflowPolicy::from(self.0.notification_overflow_policy),
+        )?;
+        stats.set_item(
+            "queued",
+            self.0.notification_queued.load(Ordering::SeqCst),
+        )?;
+        stats.set_item(
+            "dropped",
+            self.0.notification_dropped.load(Ordering::SeqCst),
+        )?;
+        stats.set_item(
+            "high_water_mark",
+            self.0.notification_high_water_mark.load(Ordering::SeqCst),
+        )?;
+        Ok(stats)
+    }
+
     /// Connect to a Kaspa node (async).
     ///
     /// Args:
@@ -551,6 +1023,32 @@ impl PyRpcClient {
     // fn clear_event_listener TODO?
     // This functionality already exists via clear_event_listener("all", callback)
 
+    /// Get an async iterator over RPC notification events, as an alternative to
+    /// callback-based `add_event_listener`.
+    ///
+    /// Args:
+    ///     event: Event type as kebab string or NotificationEvent variant. See NotificationEvent for acceptable values.
+    ///
+    /// Returns:
+    ///     NotificationStream: An async iterator yielding event dicts.
+    ///
+    /// Raises:
+    ///     Exception: If the event type is invalid.
+    fn notifications(&self, event: PyNotificationEvent) -> PyResult<PyNotificationStream> {
+        let event: NotificationEvent = event.into();
+        let channel = Channel::unbounded();
+        self.0
+            .streams
+            .lock()
+            .unwrap()
+            .entry(event)
+            .or_default()
+            .push(channel.sender.clone());
+        Ok(PyNotificationStream {
+            receiver: channel.receiver,
+        })
+    }
+
     /// Get the default RPC port for a given encoding and network type.
     ///
     /// Args:
@@ -573,6 +1071,146 @@ impl PyRpcClient {
         *self.0.callbacks.lock().unwrap() = Default::default();
         Ok(())
     }
+
+    /// Start a background keepalive loop that periodically pings the node and,
+    /// on a failed ping, self-heals by reconnecting with retry/backoff.
+    ///
+    /// Args:
+    ///     interval_seconds: Seconds between keepalive pings (default: 30).
+    ///     reconnect: Attempt to reconnect automatically on a failed ping (default: True).
+    ///
+    /// Raises:
+    ///     Exception: If a keepalive loop is already running.
+    #[pyo3(signature = (interval_seconds=None, reconnect=None))]
+    #[gen_stub(override_return_type(type_repr = "None"))]
+    fn start_keepalive<'py>(
+        &self,
+        py: Python<'py>,
+        interval_seconds: Option<u64>,
+        reconnect: Option<bool>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if self.0.keepalive_task.swap(true, Ordering::SeqCst) {
+            return Err(PyException::new_err("Keepalive loop is already running"));
+        }
+
+        let interval = Duration::from_secs(interval_seconds.unwrap_or(30));
+        let reconnect = reconnect.unwrap_or(true);
+        let ctl_receiver = self.0.keepalive_ctl.request.receiver.clone();
+        let ctl_sender = self.0.keepalive_ctl.response.sender.clone();
+        let client = self.0.client.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            loop {
+                select_biased! {
+                    _ = ctl_receiver.recv().fuse() => break,
+                    _ = tokio::time::sleep(interval).fuse() => {
+                        let ping_ok = client.ping_call(None, PingRequest::default()).await.is_ok();
+                        if !ping_ok && reconnect {
+                            let options = ConnectOptions {
+                                block_async_connect: false,
+                                strategy: ConnectStrategy::Retry,
+                                url: None,
+                                connect_timeout: None,
+                                retry_interval: None,
+                            };
+                            client.connect(Some(options)).await.ok();
+                        }
+                    }
+                }
+            }
+            ctl_sender.send(()).await.ok();
+            Ok(())
+        })
+    }
+
+    /// Stop the keepalive loop started by `start_keepalive`, if running.
+    #[gen_stub(override_return_type(type_repr = "None"))]
+    fn stop_keepalive<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.0.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            if inner.keepalive_task.load(Ordering::SeqCst) {
+                inner
+                    .keepalive_ctl
+                    .signal(())
+                    .await
+                    .map_err(|err| PyException::new_err(err.to_string()))?;
+                inner.keepalive_task.store(false, Ordering::SeqCst);
+            }
+            Ok(())
+        })
+    }
+
+    /// Cooperatively shut down the client (async).
+    ///
+    /// Transitions to a draining state that rejects new RPC submissions,
+    /// awaits completion of outstanding async calls (or `timeout`, whichever
+    /// comes first), closes all notification streams, unregisters the
+    /// notification listener and tears down the WebSocket connection -
+    /// emitting a single terminal `Disconnect` event.
+    ///
+    /// Args:
+    ///     timeout: Maximum time to wait for outstanding RPC calls to drain, in milliseconds (default: 5000).
+    ///
+    /// Raises:
+    ///     Exception: If disconnection fails.
+    #[pyo3(signature = (timeout=None))]
+    #[gen_stub(override_return_type(type_repr = "None"))]
+    fn shutdown<'py>(&self, py: Python<'py>, timeout: Option<u64>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let timeout = Duration::from_millis(timeout.unwrap_or(5_000));
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client
+                .shutdown_impl(timeout)
+                .await
+                .map_err(|err| PyException::new_err(err.to_string()))
+        })
+    }
+
+    /// Install a SIGINT/SIGTERM handler that cooperatively shuts the client down (async).
+    ///
+    /// Resolves once the signal fires and `shutdown()` has completed, so the
+    /// background Tokio task it spawns is not leaked even if the Python side
+    /// never explicitly calls `shutdown()`. Schedule this as a background task
+    /// rather than awaiting it directly.
+    ///
+    /// Raises:
+    ///     Exception: If a handler is already registered, or shutdown fails.
+    #[gen_stub(override_return_type(type_repr = "None"))]
+    fn register_shutdown_signal<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        if self.0.shutdown_signal_task.swap(true, Ordering::SeqCst) {
+            return Err(PyException::new_err(
+                "Shutdown signal handler is already registered",
+            ));
+        }
+
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            #[cfg(unix)]
+            {
+                let mut sigterm =
+                    tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                        .map_err(|err| PyException::new_err(err.to_string()))?;
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {},
+                    _ = sigterm.recv() => {},
+                }
+            }
+            #[cfg(not(unix))]
+            {
+                tokio::signal::ctrl_c()
+                    .await
+                    .map_err(|err| PyException::new_err(err.to_string()))?;
+            }
+
+            client.0.shutdown_signal_task.store(false, Ordering::SeqCst);
+            client
+                .shutdown_impl(Duration::from_secs(5))
+                .await
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+            Ok(())
+        })
+    }
 }
 
 impl PyRpcClient {
@@ -583,6 +1221,87 @@ impl PyRpcClient {
     }
 }
 
+/// Pull and dispatch exactly one already-queued notification from
+/// `notification_channel`, decrementing `notification_queued` and invoking
+/// the matching callbacks/streams.
+///
+/// Lives outside `start_notification_task`'s `select_biased!` loop so the
+/// `NotificationOverflowPolicy::Block` arm can call it directly: that arm
+/// needs to free a slot in the same bounded channel this function drains,
+/// and awaiting the loop's own `notification_receiver` arm to do so would
+/// deadlock the task against itself.
+async fn dispatch_one_queued_notification(
+    this: &PyRpcClient,
+    notification_receiver: &Receiver<kaspa_rpc_core::Notification>,
+) {
+    if let Ok(notification) = notification_receiver.recv().await {
+        this.0.notification_queued.fetch_sub(1, Ordering::SeqCst);
+        match &notification {
+            kaspa_rpc_core::Notification::UtxosChanged(utxos_changed_notification) => {
+                let event_type = notification.event_type();
+                let notification_event = NotificationEvent::Notification(event_type);
+                if let Some(handlers) = this.0.notification_callbacks(notification_event.clone()) {
+                    let UtxosChangedNotification { added, removed } = utxos_changed_notification;
+
+                    for handler in handlers.into_iter() {
+                        Python::attach(|py| {
+                            let added = serde_pyobject::to_pyobject(py, added).unwrap();
+                            let removed = serde_pyobject::to_pyobject(py, removed).unwrap();
+
+                            let event = PyDict::new(py);
+                            event.set_item("type", event_type.to_string()).unwrap();
+                            event.set_item("added", &added).unwrap();
+                            event.set_item("removed", &removed).unwrap();
+
+                            this.0.dispatch_callback(py, &handler, &event_type.to_string(), event);
+                        })
+                    }
+                }
+                let senders = this.0.notification_streams(notification_event);
+                for sender in senders {
+                    let UtxosChangedNotification { added, removed } = utxos_changed_notification;
+                    let event = Python::attach(|py| -> Py<PyDict> {
+                        let added = serde_pyobject::to_pyobject(py, added).unwrap();
+                        let removed = serde_pyobject::to_pyobject(py, removed).unwrap();
+
+                        let event = PyDict::new(py);
+                        event.set_item("type", event_type.to_string()).unwrap();
+                        event.set_item("added", &added).unwrap();
+                        event.set_item("removed", &removed).unwrap();
+                        event.unbind()
+                    });
+                    sender.send(event).await.ok();
+                }
+            }
+            _ => {
+                let event_type = notification.event_type();
+                let notification_event = NotificationEvent::Notification(event_type);
+                if let Some(handlers) = this.0.notification_callbacks(notification_event.clone()) {
+                    for handler in handlers.into_iter() {
+                        Python::attach(|py| {
+                            let event = PyDict::new(py);
+                            event.set_item("type", event_type.to_string()).unwrap();
+                            event.set_item("data", PyNotification::from(notification.clone()).to_pyobject(py).unwrap()).unwrap();
+
+                            this.0.dispatch_callback(py, &handler, &event_type.to_string(), event);
+                        });
+                    }
+                }
+                let senders = this.0.notification_streams(notification_event);
+                for sender in senders {
+                    let event = Python::attach(|py| -> Py<PyDict> {
+                        let event = PyDict::new(py);
+                        event.set_item("type", event_type.to_string()).unwrap();
+                        event.set_item("data", PyNotification::from(notification.clone()).to_pyobject(py).unwrap()).unwrap();
+                        event.unbind()
+                    });
+                    sender.send(event).await.ok();
+                }
+            }
+        }
+    }
+}
+
 impl PyRpcClient {
     // fn new_with_rpc_client() TODO
 
@@ -595,6 +1314,62 @@ impl PyRpcClient {
         &self.0.client
     }
 
+    /// Map an `event_name` as accepted by `subscribe()`/`unsubscribe()` (the
+    /// same snake_case names as the single-scope `subscribe_*`/`unsubscribe_*`
+    /// methods) to its `Scope`, pulling `addresses` / `include_accepted_transaction_ids`
+    /// out of `params` for the two parameterized scopes.
+    fn scope_from_event(event: &str, params: Option<Bound<'_, PyDict>>) -> PyResult<Scope> {
+        match event {
+            "block_added" => Ok(Scope::BlockAdded(BlockAddedScope {})),
+            "finality_conflict" => Ok(Scope::FinalityConflict(FinalityConflictScope {})),
+            "finality_conflict_resolved" => {
+                Ok(Scope::FinalityConflictResolved(FinalityConflictResolvedScope {}))
+            }
+            "new_block_template" => Ok(Scope::NewBlockTemplate(NewBlockTemplateScope {})),
+            "pruning_point_utxo_set_override" => {
+                Ok(Scope::PruningPointUtxoSetOverride(PruningPointUtxoSetOverrideScope {}))
+            }
+            "sink_blue_score_changed" => {
+                Ok(Scope::SinkBlueScoreChanged(SinkBlueScoreChangedScope {}))
+            }
+            "virtual_daa_score_changed" => {
+                Ok(Scope::VirtualDaaScoreChanged(VirtualDaaScoreChangedScope {}))
+            }
+            "utxos_changed" => {
+                let params = params.ok_or_else(|| {
+                    PyException::new_err("`utxos_changed` requires an `addresses` parameter")
+                })?;
+                let addresses = params
+                    .get_item("addresses")?
+                    .ok_or_else(|| PyException::new_err("Key `addresses` not present"))?
+                    .extract::<Vec<PyAddress>>()?
+                    .iter()
+                    .map(|address| address.0.clone())
+                    .collect();
+                Ok(Scope::UtxosChanged(UtxosChangedScope { addresses }))
+            }
+            "virtual_chain_changed" => {
+                let params = params.ok_or_else(|| {
+                    PyException::new_err(
+                        "`virtual_chain_changed` requires an `include_accepted_transaction_ids` parameter",
+                    )
+                })?;
+                let include_accepted_transaction_ids = params
+                    .get_item("include_accepted_transaction_ids")?
+                    .ok_or_else(|| {
+                        PyException::new_err("Key `include_accepted_transaction_ids` not present")
+                    })?
+                    .extract::<bool>()?;
+                Ok(Scope::VirtualChainChanged(VirtualChainChangedScope {
+                    include_accepted_transaction_ids,
+                }))
+            }
+            other => Err(PyException::new_err(format!(
+                "Unknown notification event: `{other}`"
+            ))),
+        }
+    }
+
     async fn stop_notification_task(&self) -> Result<()> {
         if self.0.notification_task.load(Ordering::SeqCst) {
             self.0.notification_ctl.signal(()).await?;
@@ -603,6 +1378,29 @@ impl PyRpcClient {
         Ok(())
     }
 
+    /// Shared implementation backing `shutdown()` and `register_shutdown_signal()`:
+    /// drain outstanding calls (bounded by `timeout`), close notification streams,
+    /// then disconnect and stop the notification task.
+    async fn shutdown_impl(&self, timeout: Duration) -> Result<()> {
+        self.0.draining.store(true, Ordering::SeqCst);
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        while self.0.in_flight.load(Ordering::SeqCst) > 0 && tokio::time::Instant::now() < deadline
+        {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        self.0.streams.lock().unwrap().clear();
+        self.0.subscriptions.lock().unwrap().clear();
+        self.0.subscription_count.store(0, Ordering::SeqCst);
+
+        self.0.client.disconnect().await?;
+        self.stop_notification_task().await?;
+
+        self.0.draining.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
     #[allow(clippy::result_large_err)]
     fn start_notification_task(&self, py: Python) -> Result<()> {
         if self.0.notification_task.load(Ordering::SeqCst) {
@@ -613,6 +1411,8 @@ impl PyRpcClient {
 
         let ctl_receiver = self.0.notification_ctl.request.receiver.clone();
         let ctl_sender = self.0.notification_ctl.response.sender.clone();
+        let raw_notification_receiver = self.0.raw_notification_channel.receiver.clone();
+        let notification_sender = self.0.notification_channel.sender.clone();
         let notification_receiver = self.0.notification_channel.receiver.clone();
         let ctl_multiplexer_channel = self
             .0
@@ -634,10 +1434,18 @@ impl PyRpcClient {
                                 Ctl::Connect => {
                                     let listener_id = this.0.client.register_new_listener(ChannelConnection::new(
                                         "kaspapy-wrpc-client-python",
-                                        this.0.notification_channel.sender.clone(),
+                                        this.0.raw_notification_channel.sender.clone(),
                                         ChannelType::Persistent,
                                     ));
                                     *this.0.listener_id.lock().unwrap() = Some(listener_id);
+
+                                    if this.0.auto_resubscribe {
+                                        for scope in this.0.subscribed_scopes() {
+                                            if let Err(err) = this.0.client.start_notify(listener_id, scope).await {
+                                                log_error!("Error resubscribing after reconnect: {:?}", err);
+                                            }
+                                        }
+                                    }
                                 }
                                 Ctl::Disconnect => {
                                     let listener_id = this.0.listener_id.lock().unwrap().take();
@@ -649,61 +1457,69 @@ impl PyRpcClient {
                             }
 
                             let event = NotificationEvent::RpcCtl(ctl);
-                            if let Some(handlers) = this.0.notification_callbacks(event) {
+                            if let Some(handlers) = this.0.notification_callbacks(event.clone()) {
                                 for handler in handlers.into_iter() {
                                     Python::attach(|py| {
                                         let event = PyDict::new(py);
                                         event.set_item("type", ctl.to_string()).unwrap();
                                         event.set_item("rpc", this.get_url()).unwrap();
 
-                                        handler.execute(py, event).unwrap_or_else(|err| panic!("{}", err));
+                                        this.0.dispatch_callback(py, &handler, &ctl.to_string(), event);
                                     });
                                 }
                             }
+                            let senders = this.0.notification_streams(event);
+                            for sender in senders {
+                                let event = Python::attach(|py| -> Py<PyDict> {
+                                    let event = PyDict::new(py);
+                                    event.set_item("type", ctl.to_string()).unwrap();
+                                    event.set_item("rpc", this.get_url()).unwrap();
+                                    event.unbind()
+                                });
+                                sender.send(event).await.ok();
+                            }
                         }
                     },
-                    msg = notification_receiver.recv().fuse() => {
-                        if let Ok(notification) = &msg {
-                            match &notification {
-                                kaspa_rpc_core::Notification::UtxosChanged(utxos_changed_notification) => {
-                                    let event_type = notification.event_type();
-                                    let notification_event = NotificationEvent::Notification(event_type);
-                                    if let Some(handlers) = this.0.notification_callbacks(notification_event) {
-                                        let UtxosChangedNotification { added, removed } = utxos_changed_notification;
-
-                                        for handler in handlers.into_iter() {
-                                            Python::attach(|py| {
-                                                let added = serde_pyobject::to_pyobject(py, added).unwrap();
-                                                let removed = serde_pyobject::to_pyobject(py, removed).unwrap();
-
-                                                let event = PyDict::new(py);
-                                                event.set_item("type", event_type.to_string()).unwrap();
-                                                event.set_item("added", &added).unwrap();
-                                                event.set_item("removed", &removed).unwrap();
-
-                                                handler.execute(py, event).unwrap_or_else(|err| panic!("{}", err));
-                                            })
+                    msg = raw_notification_receiver.recv().fuse() => {
+                        if let Ok(notification) = msg {
+                            let queued = this.0.notification_queued.load(Ordering::SeqCst);
+                            if queued >= this.0.notification_capacity {
+                                match this.0.notification_overflow_policy {
+                                    NotificationOverflowPolicy::Block => {
+                                        // `notification_channel`'s only consumer is the
+                                        // `notification_receiver` arm of this same select
+                                        // loop, so awaiting `send()` here while it's full
+                                        // would deadlock this task forever. Drain one
+                                        // already-queued notification ourselves (via the
+                                        // same dispatch the consumer arm would have done)
+                                        // to free a slot, then retry the send.
+                                        while notification_sender.try_send(notification.clone()).is_err() {
+                                            dispatch_one_queued_notification(&this, &notification_receiver).await;
                                         }
+                                        let queued = this.0.notification_queued.fetch_add(1, Ordering::SeqCst) + 1;
+                                        this.0.notification_high_water_mark.fetch_max(queued, Ordering::SeqCst);
                                     }
-                                },
-                                _ => {
-                                    let event_type = notification.event_type();
-                                    let notification_event = NotificationEvent::Notification(event_type);
-                                    if let Some(handlers) = this.0.notification_callbacks(notification_event) {
-                                        for handler in handlers.into_iter() {
-                                            Python::attach(|py| {
-                                                let event = PyDict::new(py);
-                                                event.set_item("type", event_type.to_string()).unwrap();
-                                                event.set_item("data", PyNotification::from(notification.clone()).to_pyobject(py).unwrap()).unwrap();
-
-                                                handler.execute(py, event).unwrap_or_else(|err| panic!("{}", err));
-                                            });
+                                    NotificationOverflowPolicy::DropNewest => {
+                                        let dropped = this.0.notification_dropped.fetch_add(1, Ordering::SeqCst) + 1;
+                                        this.0.dispatch_lagged(dropped).await;
+                                    }
+                                    NotificationOverflowPolicy::DropOldest => {
+                                        notification_receiver.try_recv().ok();
+                                        this.0.notification_queued.fetch_sub(1, Ordering::SeqCst);
+                                        let dropped = this.0.notification_dropped.fetch_add(1, Ordering::SeqCst) + 1;
+                                        if notification_sender.try_send(notification).is_ok() {
+                                            this.0.notification_queued.fetch_add(1, Ordering::SeqCst);
                                         }
+                                        this.0.dispatch_lagged(dropped).await;
                                     }
                                 }
+                            } else if notification_sender.try_send(notification).is_ok() {
+                                let queued = this.0.notification_queued.fetch_add(1, Ordering::SeqCst) + 1;
+                                this.0.notification_high_water_mark.fetch_max(queued, Ordering::SeqCst);
                             }
                         }
-                    }
+                    },
+                    _ = dispatch_one_queued_notification(&this, &notification_receiver).fuse() => {}
                     _ = ctl_receiver.recv().fuse() => {
                         break;
                     },
@@ -744,17 +1560,22 @@ impl PyRpcClient {
         addresses: Vec<PyAddress>,
     ) -> PyResult<Bound<'py, PyAny>> {
         if let Some(listener_id) = self.listener_id() {
+            self.0.try_reserve_subscription()?;
             let client = self.0.client.clone();
+            let inner = self.0.clone();
             let addresses = addresses.iter().map(|a| a.0.clone()).collect();
             pyo3_async_runtimes::tokio::future_into_py(py, async move {
-                client
-                    .start_notify(
-                        listener_id,
-                        Scope::UtxosChanged(UtxosChangedScope { addresses }),
-                    )
-                    .await
-                    .map_err(|err| PyException::new_err(err.to_string()))?;
-                Ok(())
+                let scope = Scope::UtxosChanged(UtxosChangedScope { addresses });
+                match client.start_notify(listener_id, scope.clone()).await {
+                    Ok(()) => {
+                        inner.record_subscribe(scope);
+                        Ok(())
+                    }
+                    Err(err) => {
+                        inner.release_subscription();
+                        Err(PyRpcError::from(err).into())
+                    }
+                }
             })
         } else {
             Err(PyException::new_err("RPC subscribe on a closed connection"))
@@ -776,15 +1597,16 @@ impl PyRpcClient {
     ) -> PyResult<Bound<'py, PyAny>> {
         if let Some(listener_id) = self.listener_id() {
             let client = self.0.client.clone();
+            let inner = self.0.clone();
             let addresses = addresses.iter().map(|a| a.0.clone()).collect();
             pyo3_async_runtimes::tokio::future_into_py(py, async move {
+                let scope = Scope::UtxosChanged(UtxosChangedScope { addresses });
                 client
-                    .stop_notify(
-                        listener_id,
-                        Scope::UtxosChanged(UtxosChangedScope { addresses }),
-                    )
+                    .stop_notify(listener_id, scope.clone())
                     .await
-                    .map_err(|err| PyException::new_err(err.to_string()))?;
+                    .map_err(PyRpcError::from)?;
+                inner.record_unsubscribe(&scope);
+                inner.release_subscription();
                 Ok(())
             })
         } else {
@@ -794,6 +1616,58 @@ impl PyRpcClient {
         }
     }
 
+    /// Subscribe to UTXO changes for specific addresses and consume them as an
+    /// async iterator, instead of registering a callback (async).
+    ///
+    /// Equivalent to calling `subscribe_utxos_changed` and then filtering
+    /// `notifications(NotificationEvent.UtxosChanged)` down to this
+    /// subscription's addresses, bundled into a single call.
+    ///
+    /// Args:
+    ///     addresses: List of addresses to monitor.
+    ///
+    /// Returns:
+    ///     NotificationStream: An async iterator yielding UTXO-changed events.
+    ///
+    /// Raises:
+    ///     Exception: If not connected or subscription fails.
+    #[gen_stub(override_return_type(type_repr = "NotificationStream"))]
+    fn utxos_changed_stream<'py>(
+        &self,
+        py: Python<'py>,
+        addresses: Vec<PyAddress>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if let Some(listener_id) = self.listener_id() {
+            self.0.try_reserve_subscription()?;
+            let client = self.0.client.clone();
+            let inner = self.0.clone();
+            let addresses = addresses.iter().map(|a| a.0.clone()).collect();
+            pyo3_async_runtimes::tokio::future_into_py(py, async move {
+                let scope = Scope::UtxosChanged(UtxosChangedScope { addresses });
+                if let Err(err) = client.start_notify(listener_id, scope.clone()).await {
+                    inner.release_subscription();
+                    return Err(PyRpcError::from(err).into());
+                }
+                inner.record_subscribe(scope);
+
+                let channel = Channel::unbounded();
+                inner
+                    .streams
+                    .lock()
+                    .unwrap()
+                    .entry(NotificationEvent::Notification(EventType::UtxosChanged))
+                    .or_default()
+                    .push(channel.sender.clone());
+
+                Ok(PyNotificationStream {
+                    receiver: channel.receiver,
+                })
+            })
+        } else {
+            Err(PyException::new_err("RPC subscribe on a closed connection"))
+        }
+    }
+
     /// Subscribe to virtual chain changes (async).
     ///
     /// Args:
@@ -808,18 +1682,23 @@ impl PyRpcClient {
         include_accepted_transaction_ids: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         if let Some(listener_id) = self.listener_id() {
+            self.0.try_reserve_subscription()?;
             let client = self.0.client.clone();
+            let inner = self.0.clone();
             pyo3_async_runtimes::tokio::future_into_py(py, async move {
-                client
-                    .start_notify(
-                        listener_id,
-                        Scope::VirtualChainChanged(VirtualChainChangedScope {
-                            include_accepted_transaction_ids,
-                        }),
-                    )
-                    .await
-                    .map_err(|err| PyException::new_err(err.to_string()))?;
-                Ok(())
+                let scope = Scope::VirtualChainChanged(VirtualChainChangedScope {
+                    include_accepted_transaction_ids,
+                });
+                match client.start_notify(listener_id, scope.clone()).await {
+                    Ok(()) => {
+                        inner.record_subscribe(scope);
+                        Ok(())
+                    }
+                    Err(err) => {
+                        inner.release_subscription();
+                        Err(PyRpcError::from(err).into())
+                    }
+                }
             })
         } else {
             Err(PyException::new_err("RPC subscribe on a closed connection"))
@@ -841,16 +1720,17 @@ impl PyRpcClient {
     ) -> PyResult<Bound<'py, PyAny>> {
         if let Some(listener_id) = self.listener_id() {
             let client = self.0.client.clone();
+            let inner = self.0.clone();
             pyo3_async_runtimes::tokio::future_into_py(py, async move {
+                let scope = Scope::VirtualChainChanged(VirtualChainChangedScope {
+                    include_accepted_transaction_ids,
+                });
                 client
-                    .stop_notify(
-                        listener_id,
-                        Scope::VirtualChainChanged(VirtualChainChangedScope {
-                            include_accepted_transaction_ids,
-                        }),
-                    )
+                    .stop_notify(listener_id, scope.clone())
                     .await
-                    .map_err(|err| PyException::new_err(err.to_string()))?;
+                    .map_err(PyRpcError::from)?;
+                inner.record_unsubscribe(&scope);
+                inner.release_subscription();
                 Ok(())
             })
         } else {
@@ -876,11 +1756,21 @@ macro_rules! build_wrpc_python_subscriptions {
                     #[gen_stub(override_return_type(type_repr="None"))]
                     fn [<subscribe_ $scope:snake>]<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
                         if let Some(listener_id) = self.listener_id() {
+                            self.0.try_reserve_subscription()?;
                             let client = self.0.client.clone();
+                            let inner = self.0.clone();
                             pyo3_async_runtimes::tokio::future_into_py(py, async move {
-                                client.start_notify(listener_id, Scope::$scope([<$scope Scope>] {})).await
-                                    .map_err(|err| PyException::new_err(err.to_string()))?;
-                                Ok(())
+                                let scope = Scope::$scope([<$scope Scope>] {});
+                                match client.start_notify(listener_id, scope.clone()).await {
+                                    Ok(()) => {
+                                        inner.record_subscribe(scope);
+                                        Ok(())
+                                    }
+                                    Err(err) => {
+                                        inner.release_subscription();
+                                        Err(PyRpcError::from(err).into())
+                                    }
+                                }
                             })
                         } else {
                             Err(PyException::new_err("RPC subscribe on a closed connection"))
@@ -891,9 +1781,13 @@ macro_rules! build_wrpc_python_subscriptions {
                     fn [<unsubscribe_ $scope:snake>]<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
                         if let Some(listener_id) = self.listener_id() {
                             let client = self.0.client.clone();
+                            let inner = self.0.clone();
                             pyo3_async_runtimes::tokio::future_into_py(py, async move {
-                                client.stop_notify(listener_id, Scope::$scope([<$scope Scope>] {})).await
-                                    .map_err(|err| PyException::new_err(err.to_string()))?;
+                                let scope = Scope::$scope([<$scope Scope>] {});
+                                client.stop_notify(listener_id, scope.clone()).await
+                                    .map_err(PyRpcError::from)?;
+                                inner.record_unsubscribe(&scope);
+                                inner.release_subscription();
                                 Ok(())
                             })
                         } else {
@@ -936,17 +1830,25 @@ macro_rules! build_wrpc_python_interface {
                         py: Python<'py>,
                         request: Option<Bound<'_, PyDict>>
                     ) -> PyResult<Bound<'py, PyAny>> {
+                        if self.0.draining.load(Ordering::SeqCst) {
+                            return Err(PyException::new_err(
+                                "RpcClient is shutting down: no new requests are accepted",
+                            ));
+                        }
+
                         let client = self.0.client.clone();
+                        let in_flight = self.0.in_flight.clone();
 
                         let request: [<Py $name Request>] = request
                             .unwrap_or_else(|| PyDict::new(py))
                             .try_into()?;
 
                         pyo3_async_runtimes::tokio::future_into_py(py, async move {
+                            let _guard = InFlightGuard::new(in_flight);
                             let response: [<$name Response>] = client
                                 .[<$name:snake _call>](None, request.0)
                                 .await
-                                .map_err(|err| PyException::new_err(err.to_string()))?;
+                                .map_err(PyRpcError::from)?;
 
                             Python::attach(|py| {
                                 Ok(serde_pyobject::to_pyobject(py, &response)?.unbind())
@@ -995,15 +1897,23 @@ macro_rules! build_wrpc_python_interface_with_args {
                         py: Python<'py>,
                         request: Bound<'_, PyDict>
                     ) -> PyResult<Bound<'py, PyAny>> {
+                        if self.0.draining.load(Ordering::SeqCst) {
+                            return Err(PyException::new_err(
+                                "RpcClient is shutting down: no new requests are accepted",
+                            ));
+                        }
+
                         let client = self.0.client.clone();
+                        let in_flight = self.0.in_flight.clone();
 
                         let request: [<Py $name Request>] = request.try_into()?;
 
                         pyo3_async_runtimes::tokio::future_into_py(py, async move {
+                            let _guard = InFlightGuard::new(in_flight);
                             let response: [<$name Response>] = client
                                 .[<$name:snake _call>](None, request.0)
                                 .await
-                                .map_err(|err| PyException::new_err(err.to_string()))?;
+                                .map_err(PyRpcError::from)?;
 
                             Python::attach(|py| {
                                 Ok(serde_pyobject::to_pyobject(py, &response)?.unbind())
@@ -1043,3 +1953,328 @@ build_wrpc_python_interface_with_args!([
     SubmitTransactionReplacement,
     Unban,
 ]);
+
+// Macro generating a `BatchRequest` enum with one variant per RPC method name,
+// plus the synchronous (dict -> typed request) conversion and the async
+// (typed request -> typed response, serialized back to a Python object) dispatch.
+//
+// Splitting `batch()`'s work this way avoids ever boxing a `dyn Future`: every
+// request is converted to its concrete native type up front (while the GIL is
+// held), and the single `dispatch` match arm per call keeps each leg of the
+// `join_all` below a plain, independently-typed `async` block.
+macro_rules! build_wrpc_batch_requests {
+    ([$($name:ident),* $(,)?]) => {
+        paste! {
+            enum BatchRequest {
+                $( $name([<$name Request>]), )*
+            }
+
+            impl BatchRequest {
+                fn try_from_method(method: &str, params: Bound<'_, PyDict>) -> PyResult<Self> {
+                    match method {
+                        $(
+                            stringify!([<$name:snake>]) => {
+                                let request: [<Py $name Request>] = params.try_into()?;
+                                Ok(BatchRequest::$name(request.0))
+                            }
+                        )*
+                        other => Err(PyException::new_err(format!("Unknown RPC method: `{other}`"))),
+                    }
+                }
+
+                async fn dispatch(self, client: &KaspaRpcClient) -> PyResult<Py<PyAny>> {
+                    match self {
+                        $(
+                            BatchRequest::$name(request) => {
+                                let response: [<$name Response>] = client
+                                    .[<$name:snake _call>](None, request)
+                                    .await
+                                    .map_err(PyRpcError::from)?;
+
+                                Python::attach(|py| {
+                                    Ok(serde_pyobject::to_pyobject(py, &response)?.unbind())
+                                })
+                            }
+                        )*
+                    }
+                }
+            }
+        }
+    };
+}
+
+build_wrpc_batch_requests!([
+    GetBlockCount,
+    GetBlockDagInfo,
+    GetCoinSupply,
+    GetConnectedPeerInfo,
+    GetInfo,
+    GetPeerAddresses,
+    GetMetrics,
+    GetConnections,
+    GetSink,
+    GetSinkBlueScore,
+    Ping,
+    Shutdown,
+    GetServerInfo,
+    GetSyncStatus,
+    GetFeeEstimate,
+    GetCurrentNetwork,
+    GetSystemInfo,
+    AddPeer,
+    Ban,
+    EstimateNetworkHashesPerSecond,
+    GetBalanceByAddress,
+    GetBalancesByAddresses,
+    GetBlock,
+    GetBlocks,
+    GetBlockTemplate,
+    GetCurrentBlockColor,
+    GetDaaScoreTimestampEstimate,
+    GetFeeEstimateExperimental,
+    GetHeaders,
+    GetMempoolEntries,
+    GetMempoolEntriesByAddresses,
+    GetMempoolEntry,
+    GetSubnetwork,
+    GetUtxosByAddresses,
+    GetUtxoReturnAddress,
+    GetVirtualChainFromBlock,
+    GetVirtualChainFromBlockV2,
+    ResolveFinalityConflict,
+    SubmitBlock,
+    SubmitTransaction,
+    SubmitTransactionReplacement,
+    Unban,
+]);
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyRpcClient {
+    /// Dispatch many RPC requests concurrently and collect their responses in order.
+    ///
+    /// Each request is issued as soon as `batch()` is awaited rather than one at a
+    /// time, so e.g. fanning `get_balance_by_address` out across many addresses
+    /// only costs a single round trip's worth of latency instead of one per address.
+    ///
+    /// Args:
+    ///     requests: A list of `(method_name, params)` tuples, where `method_name`
+    ///         is the snake_case RPC method name (e.g. `"get_balance_by_address"`)
+    ///         and `params` is the same dict that method's own call would take
+    ///         (or None for methods that take no parameters).
+    ///
+    /// Returns:
+    ///     list: One entry per request, in the same order as `requests`. A
+    ///     successful call contributes its response dict; a failed one
+    ///     contributes `{"error": str}` instead, so a single bad request does
+    ///     not abort the rest of the batch.
+    ///
+    /// Raises:
+    ///     Exception: If the client is shutting down.
+    #[gen_stub(override_return_type(type_repr = "list"))]
+    fn batch<'py>(
+        &self,
+        py: Python<'py>,
+        requests: Vec<(String, Option<Bound<'py, PyDict>>)>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if self.0.draining.load(Ordering::SeqCst) {
+            return Err(PyException::new_err(
+                "RpcClient is shutting down: no new requests are accepted",
+            ));
+        }
+
+        let client = self.0.client.clone();
+        let in_flight = self.0.in_flight.clone();
+
+        let requests: Vec<PyResult<BatchRequest>> = requests
+            .into_iter()
+            .map(|(method, params)| {
+                let params = params.unwrap_or_else(|| PyDict::new(py));
+                BatchRequest::try_from_method(&method, params)
+            })
+            .collect();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let _guard = InFlightGuard::new(in_flight);
+
+            let responses = futures::future::join_all(requests.into_iter().map(|request| {
+                let client = client.clone();
+                async move {
+                    match request {
+                        Ok(request) => request.dispatch(&client).await,
+                        Err(err) => Err(err),
+                    }
+                }
+            }))
+            .await;
+
+            Python::attach(|py| {
+                let results = PyList::empty(py);
+                for response in responses {
+                    match response {
+                        Ok(value) => results.append(value)?,
+                        Err(err) => {
+                            let error = PyDict::new(py);
+                            error.set_item("error", err.to_string())?;
+                            results.append(error)?;
+                        }
+                    }
+                }
+                Ok(results.unbind())
+            })
+        })
+    }
+
+    /// Dispatch a single RPC request by its snake_case method name.
+    ///
+    /// This is the generic counterpart to the per-method wrappers above
+    /// (`get_block_count()`, `get_balance_by_address()`, ...): it reuses the
+    /// same method-name registry `batch()` dispatches through, so a node
+    /// method added to that registry is immediately reachable here without
+    /// a new Python-callable method being hand-written for it.
+    ///
+    /// Args:
+    ///     method: The snake_case RPC method name (e.g. `"get_block_count"`).
+    ///     params: The same dict that method's own call would take (or None
+    ///         for methods that take no parameters).
+    ///
+    /// Returns:
+    ///     dict: The response, keyed the same way the typed wrapper's
+    ///     response would be.
+    ///
+    /// Raises:
+    ///     Exception: If the client is shutting down, `method` is unknown, or
+    ///         the call itself fails.
+    #[pyo3(signature = (method, params=None))]
+    #[gen_stub(override_return_type(type_repr = "dict"))]
+    fn call<'py>(
+        &self,
+        py: Python<'py>,
+        method: String,
+        params: Option<Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if self.0.draining.load(Ordering::SeqCst) {
+            return Err(PyException::new_err(
+                "RpcClient is shutting down: no new requests are accepted",
+            ));
+        }
+
+        let client = self.0.client.clone();
+        let in_flight = self.0.in_flight.clone();
+        let params = params.unwrap_or_else(|| PyDict::new(py));
+        let request = BatchRequest::try_from_method(&method, params)?;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let _guard = InFlightGuard::new(in_flight);
+            request.dispatch(&client).await
+        })
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyRpcClient {
+    /// Subscribe to several notification scopes in one awaited call, instead of
+    /// `await`ing a separate `subscribe_*` method per scope.
+    ///
+    /// Args:
+    ///     events: A list of `(event_name, params)` tuples. `event_name` is the
+    ///         same snake_case scope name used by the single-scope `subscribe_*`
+    ///         methods (e.g. `"block_added"`, `"virtual_daa_score_changed"`);
+    ///         `params` is `None` for scopes that take no arguments, or a dict
+    ///         with an `addresses` key for `"utxos_changed"` / an
+    ///         `include_accepted_transaction_ids` key for `"virtual_chain_changed"`.
+    ///
+    /// Raises:
+    ///     Exception: If not connected, an event name is unknown, or any scope
+    ///     fails to subscribe - in which case every scope this call already
+    ///     subscribed to is unsubscribed again before the error is raised.
+    #[gen_stub(override_return_type(type_repr = "None"))]
+    fn subscribe<'py>(
+        &self,
+        py: Python<'py>,
+        events: Vec<(String, Option<Bound<'py, PyDict>>)>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let Some(listener_id) = self.listener_id() else {
+            return Err(PyException::new_err("RPC subscribe on a closed connection"));
+        };
+
+        let scopes = events
+            .into_iter()
+            .map(|(event, params)| Self::scope_from_event(&event, params))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let client = self.0.client.clone();
+        let inner = self.0.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut subscribed = Vec::with_capacity(scopes.len());
+            for scope in scopes {
+                if let Err(err) = inner.try_reserve_subscription() {
+                    inner.rollback_scopes(&client, listener_id, subscribed, false).await;
+                    return Err(err);
+                }
+                match client.start_notify(listener_id, scope.clone()).await {
+                    Ok(()) => {
+                        inner.record_subscribe(scope.clone());
+                        subscribed.push(scope);
+                    }
+                    Err(err) => {
+                        inner.release_subscription();
+                        inner.rollback_scopes(&client, listener_id, subscribed, false).await;
+                        return Err(PyRpcError::from(err).into());
+                    }
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Unsubscribe from several notification scopes in one awaited call, instead
+    /// of `await`ing a separate `unsubscribe_*` method per scope.
+    ///
+    /// Args:
+    ///     events: A list of `(event_name, params)` tuples, in the same form
+    ///         accepted by [`PyRpcClient::subscribe`].
+    ///
+    /// Raises:
+    ///     Exception: If not connected, an event name is unknown, or any scope
+    ///     fails to unsubscribe - in which case every scope this call already
+    ///     unsubscribed from is subscribed to again before the error is raised.
+    #[gen_stub(override_return_type(type_repr = "None"))]
+    fn unsubscribe<'py>(
+        &self,
+        py: Python<'py>,
+        events: Vec<(String, Option<Bound<'py, PyDict>>)>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let Some(listener_id) = self.listener_id() else {
+            return Err(PyException::new_err(
+                "RPC unsubscribe on a closed connection",
+            ));
+        };
+
+        let scopes = events
+            .into_iter()
+            .map(|(event, params)| Self::scope_from_event(&event, params))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let client = self.0.client.clone();
+        let inner = self.0.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut unsubscribed = Vec::with_capacity(scopes.len());
+            for scope in scopes {
+                match client.stop_notify(listener_id, scope.clone()).await {
+                    Ok(()) => {
+                        inner.record_unsubscribe(&scope);
+                        inner.release_subscription();
+                        unsubscribed.push(scope);
+                    }
+                    Err(err) => {
+                        inner.rollback_scopes(&client, listener_id, unsubscribed, true).await;
+                        return Err(PyRpcError::from(err).into());
+                    }
+                }
+            }
+            Ok(())
+        })
+    }
+}
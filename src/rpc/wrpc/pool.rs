@@ -0,0 +1,166 @@
+use crate::consensus::core::network::PyNetworkId;
+use crate::rpc::encoding::PyEncoding;
+use crate::rpc::wrpc::client::PyRpcClient;
+use kaspa_wrpc_client::client::ConnectOptions;
+use pyo3::{exceptions::PyException, prelude::*};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// A pool of `RpcClient` connections to multiple nodes, providing failover
+/// and per-endpoint routing.
+///
+/// Each endpoint gets its own independent `RpcClient`; `client()` returns the
+/// first currently-connected endpoint (round-robin starting from the last
+/// active one), so callers that always go through `pool.client()` transparently
+/// fail over when their current endpoint drops. `endpoint()` bypasses failover
+/// to target one node directly, e.g. to compare responses or pin a subscription.
+#[gen_stub_pyclass]
+#[pyclass(name = "RpcClientPool")]
+#[derive(Clone)]
+pub struct PyRpcClientPool {
+    clients: Vec<PyRpcClient>,
+    active: Arc<AtomicUsize>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyRpcClientPool {
+    /// Create a connection pool over a fixed list of node URLs.
+    ///
+    /// Args:
+    ///     urls: The WebSocket URLs of the nodes to pool.
+    ///     encoding: RPC encoding - either a string ("borsh" or "json") or an Encoding enum variant (default: "borsh").
+    ///     network_id: Network identifier (default: "mainnet").
+    ///
+    /// Returns:
+    ///     RpcClientPool: A new RpcClientPool instance.
+    ///
+    /// Raises:
+    ///     Exception: If any endpoint's client cannot be constructed, or `urls` is empty.
+    #[new]
+    #[pyo3(signature = (urls, encoding=None, network_id=None))]
+    pub fn ctor(
+        urls: Vec<String>,
+        #[gen_stub(override_type(type_repr = "str | Encoding | None = Encoding.Borsh"))]
+        encoding: Option<PyEncoding>,
+        network_id: Option<PyNetworkId>,
+    ) -> PyResult<Self> {
+        if urls.is_empty() {
+            return Err(PyException::new_err(
+                "RpcClientPool requires at least one endpoint URL",
+            ));
+        }
+
+        let clients = urls
+            .into_iter()
+            .map(|url| {
+                PyRpcClient::new(
+                    None,
+                    Some(url),
+                    Some(encoding.clone().unwrap_or(PyEncoding::Borsh)),
+                    network_id.clone().map(Into::into),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        Ok(Self {
+            clients,
+            active: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// The number of endpoints managed by this pool.
+    #[getter]
+    pub fn get_endpoint_count(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Connect to every endpoint in the pool (async).
+    ///
+    /// Individual endpoint failures are tolerated; an error is only raised
+    /// if every endpoint fails to connect.
+    ///
+    /// Raises:
+    ///     Exception: If every endpoint fails to connect.
+    #[gen_stub(override_return_type(type_repr = "None"))]
+    pub fn connect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let clients = self.clients.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let options = ConnectOptions {
+                block_async_connect: true,
+                ..Default::default()
+            };
+
+            let mut last_error = None;
+            let mut connected = 0;
+            for client in clients.iter() {
+                match client.client().connect(Some(options.clone())).await {
+                    Ok(()) => connected += 1,
+                    Err(err) => last_error = Some(err.to_string()),
+                }
+            }
+
+            if connected == 0 {
+                return Err(PyException::new_err(format!(
+                    "All endpoints failed to connect: {}",
+                    last_error.unwrap_or_else(|| "unknown error".to_string())
+                )));
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Get the currently-healthy client, failing over to the next endpoint
+    /// (in round-robin order, starting from the last active one) if needed.
+    ///
+    /// Returns:
+    ///     RpcClient: A connected client.
+    ///
+    /// Raises:
+    ///     Exception: If no endpoint in the pool is currently connected.
+    pub fn client(&self) -> PyResult<PyRpcClient> {
+        let count = self.clients.len();
+        let start = self.active.load(Ordering::SeqCst);
+        for offset in 0..count {
+            let index = (start + offset) % count;
+            if self.clients[index].client().is_connected() {
+                self.active.store(index, Ordering::SeqCst);
+                return Ok(self.clients[index].clone());
+            }
+        }
+        Err(PyException::new_err(
+            "No connected endpoint is available in the pool",
+        ))
+    }
+
+    /// Get the client for a specific endpoint, bypassing failover.
+    ///
+    /// Args:
+    ///     index: The endpoint index, in the order passed to the constructor.
+    ///
+    /// Returns:
+    ///     RpcClient: The client for that endpoint.
+    ///
+    /// Raises:
+    ///     Exception: If `index` is out of range.
+    pub fn endpoint(&self, index: usize) -> PyResult<PyRpcClient> {
+        self.clients
+            .get(index)
+            .cloned()
+            .ok_or_else(|| PyException::new_err(format!("Endpoint index {index} is out of range")))
+    }
+
+    /// Get the URL of every endpoint in the pool, in order.
+    ///
+    /// Returns:
+    ///     list[str | None]: The endpoint URLs (None for an endpoint not yet connected).
+    pub fn urls(&self) -> Vec<Option<String>> {
+        self.clients.iter().map(|client| client.client().url()).collect()
+    }
+}
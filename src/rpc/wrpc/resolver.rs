@@ -1,8 +1,57 @@
-use crate::{consensus::core::network::PyNetworkId, rpc::encoding::PyEncoding};
-use kaspa_wrpc_client::Resolver;
-use pyo3::{exceptions::PyException, prelude::*};
+use crate::{
+    consensus::core::network::PyNetworkId,
+    rpc::{encoding::PyEncoding, wrpc::client::PyRpcClient},
+};
+use kaspa_wrpc_client::{Resolver, client::ConnectOptions};
+use pyo3::{exceptions::PyException, prelude::*, types::PyDict};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Per-URL health stats gathered by `Resolver.get_ranked_nodes`.
+#[derive(Clone, Debug)]
+struct NodeStats {
+    url: String,
+    /// Round-trip latency of the probe connection, if it succeeded.
+    latency_ms: Option<u64>,
+    reachable: bool,
+}
+
+impl NodeStats {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("url", &self.url)?;
+        dict.set_item("latency_ms", self.latency_ms)?;
+        dict.set_item("reachable", self.reachable)?;
+        Ok(dict)
+    }
+}
+
+/// Probe a single URL: open a client, connect with a bounded timeout, time
+/// the round trip, then tear the probe connection back down.
+async fn probe_node(url: String, encoding: PyEncoding, network_id: crate::consensus::core::network::PyNetworkId) -> NodeStats {
+    let probe = PyRpcClient::new(None, Some(url.clone()), Some(encoding), Some(network_id.into()), None, None, None, None);
+
+    let Ok(probe) = probe else {
+        return NodeStats { url, latency_ms: None, reachable: false };
+    };
+
+    let options = ConnectOptions {
+        block_async_connect: true,
+        connect_timeout: Some(Duration::from_secs(5)),
+        ..Default::default()
+    };
+
+    let start = Instant::now();
+    let result = probe.client().connect(Some(options)).await;
+    let elapsed = start.elapsed();
+    let _ = probe.client().disconnect().await;
+
+    match result {
+        Ok(()) => NodeStats { url, latency_ms: Some(elapsed.as_millis() as u64), reachable: true },
+        Err(_) => NodeStats { url, latency_ms: None, reachable: false },
+    }
+}
 
 /// A resolver for discovering Kaspa RPC node endpoints.
 ///
@@ -122,7 +171,159 @@ impl PyResolver {
         })
     }
 
-    // fn connect() TODO
+    /// Probe every candidate endpoint in parallel and return them ranked
+    /// best-first by reachability then round-trip latency (async).
+    ///
+    /// Args:
+    ///     encoding: RPC encoding - either a string ("borsh" or "json") or an Encoding enum variant.
+    ///     network_id: The network to probe nodes for.
+    ///
+    /// Returns:
+    ///     list[dict]: Per-URL stats (`url`, `latency_ms`, `reachable`), healthy
+    ///     and fastest nodes first; unreachable nodes are listed last.
+    #[gen_stub(override_return_type(type_repr = "list[dict]"))]
+    fn get_ranked_nodes<'py>(
+        &self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "str | Encoding"))] encoding: PyEncoding,
+        network_id: PyNetworkId,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let urls = self.urls();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let probes = urls
+                .into_iter()
+                .map(|url| probe_node(url, encoding.clone(), network_id.clone()));
+            let mut stats = futures::future::join_all(probes).await;
+            stats.sort_by(|a, b| match (a.reachable, b.reachable) {
+                (true, true) => a.latency_ms.cmp(&b.latency_ms),
+                (true, false) => std::cmp::Ordering::Less,
+                (false, true) => std::cmp::Ordering::Greater,
+                (false, false) => std::cmp::Ordering::Equal,
+            });
+
+            Python::attach(|py| {
+                let list = stats
+                    .iter()
+                    .map(|s| s.to_dict(py))
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(list.into_pyobject(py)?.unbind().into_any())
+            })
+        })
+    }
+
+    /// Get the single lowest-latency healthy node (async).
+    ///
+    /// Args:
+    ///     encoding: RPC encoding - either a string ("borsh" or "json") or an Encoding enum variant.
+    ///     network_id: The network to find a node for.
+    ///
+    /// Returns:
+    ///     dict: The best-ranked node's stats (`url`, `latency_ms`, `reachable`).
+    ///
+    /// Raises:
+    ///     Exception: If no candidate endpoint is reachable.
+    #[gen_stub(override_return_type(type_repr = "dict"))]
+    fn get_best_node<'py>(
+        &self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "str | Encoding"))] encoding: PyEncoding,
+        network_id: PyNetworkId,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let urls = self.urls();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let probes = urls
+                .into_iter()
+                .map(|url| probe_node(url, encoding.clone(), network_id.clone()));
+            let stats = futures::future::join_all(probes).await;
+            let best = stats
+                .into_iter()
+                .filter(|s| s.reachable)
+                .min_by_key(|s| s.latency_ms)
+                .ok_or_else(|| PyException::new_err("No candidate endpoint is reachable"))?;
+
+            Python::attach(|py| Ok(best.to_dict(py)?.unbind()))
+        })
+    }
+
+    /// Resolve a node and connect to it, with failover across ranked
+    /// endpoints and exponential-backoff retry on transient disconnects (async).
+    ///
+    /// On connection failure, the ranked URL list (from `get_ranked_nodes`)
+    /// is iterated in order; if every endpoint fails, the connect attempt is
+    /// retried with a backoff delay that doubles from `base_delay_ms` up to
+    /// `max_delay_ms`, for up to `max_attempts` rounds.
+    ///
+    /// Args:
+    ///     encoding: RPC encoding - either a string ("borsh" or "json") or an Encoding enum variant.
+    ///     network_id: The network to connect within.
+    ///     base_delay_ms: Initial backoff delay in milliseconds (default: 250).
+    ///     max_delay_ms: Maximum backoff delay in milliseconds (default: 30000).
+    ///     max_attempts: Maximum number of failover rounds (default: 5).
+    ///
+    /// Returns:
+    ///     RpcClient: A connected client for the best reachable endpoint.
+    ///
+    /// Raises:
+    ///     Exception: If every endpoint fails to connect after `max_attempts` rounds.
+    #[pyo3(signature = (encoding, network_id, base_delay_ms=None, max_delay_ms=None, max_attempts=None))]
+    #[gen_stub(override_return_type(type_repr = "RpcClient"))]
+    fn connect<'py>(
+        &self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "str | Encoding"))] encoding: PyEncoding,
+        network_id: PyNetworkId,
+        base_delay_ms: Option<u64>,
+        max_delay_ms: Option<u64>,
+        max_attempts: Option<u32>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let urls = self.urls();
+        let base_delay_ms = base_delay_ms.unwrap_or(250);
+        let max_delay_ms = max_delay_ms.unwrap_or(30_000);
+        let max_attempts = max_attempts.unwrap_or(5);
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut delay = base_delay_ms;
+            let mut last_error = None;
+
+            for attempt in 0..max_attempts.max(1) {
+                let probes = urls
+                    .iter()
+                    .cloned()
+                    .map(|url| probe_node(url, encoding.clone(), network_id.clone()));
+                let mut stats = futures::future::join_all(probes).await;
+                stats.sort_by_key(|s| s.latency_ms.unwrap_or(u64::MAX));
+
+                for stats in stats.into_iter().filter(|s| s.reachable) {
+                    let client = PyRpcClient::new(
+                        None,
+                        Some(stats.url.clone()),
+                        Some(encoding.clone()),
+                        Some(network_id.clone().into()),
+                        None,
+                        None,
+                        None,
+                        None,
+                    )?;
+                    let options = ConnectOptions { block_async_connect: true, ..Default::default() };
+                    match client.client().connect(Some(options)).await {
+                        Ok(()) => return Ok(client),
+                        Err(err) => last_error = Some(err.to_string()),
+                    }
+                }
+
+                if attempt + 1 < max_attempts {
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    delay = (delay * 2).min(max_delay_ms);
+                }
+            }
+
+            Err(PyException::new_err(format!(
+                "Failed to connect after {} attempt(s): {}",
+                max_attempts,
+                last_error.unwrap_or_else(|| "no endpoint was reachable".to_string())
+            )))
+        })
+    }
 }
 
 impl From<PyResolver> for Resolver {
@@ -7,18 +7,50 @@ crate::wrap_unit_enum_for_py!(
     /// BIP-39 mnemonic word list language.
     ///
     /// Variants:
-    ///     English: English word list (2048 words).
+    ///     - English: English word list (2048 words).
+    ///     - SimplifiedChinese: Simplified Chinese word list.
+    ///     - TraditionalChinese: Traditional Chinese word list.
+    ///     - Czech: Czech word list.
+    ///     - French: French word list.
+    ///     - Italian: Italian word list.
+    ///     - Japanese: Japanese word list.
+    ///     - Korean: Korean word list.
+    ///     - Spanish: Spanish word list.
+    ///     - Portuguese: Portuguese word list.
+    ///
+    /// The derived seed only depends on the entropy, not the word list, so a
+    /// mnemonic generated in one language derives the same keys as the
+    /// equivalent phrase restored in any other.
     ///
     /// Category: Wallet/Keys
-    PyLanguage, "Language", Language, { English }
-);
+    PyLanguage, "Language", Language, {
+        English,
+        SimplifiedChinese,
+        TraditionalChinese,
+        Czech,
+        French,
+        Italian,
+        Japanese,
+        Korean,
+        Spanish,
+        Portuguese
+});
 
 impl FromStr for PyLanguage {
     type Err = PyErr;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
+        match s.to_lowercase().replace(['-', '_', ' '], "").as_str() {
             "english" => Ok(PyLanguage::English),
+            "simplifiedchinese" | "chinesesimplified" => Ok(PyLanguage::SimplifiedChinese),
+            "traditionalchinese" | "chinesetraditional" => Ok(PyLanguage::TraditionalChinese),
+            "czech" => Ok(PyLanguage::Czech),
+            "french" => Ok(PyLanguage::French),
+            "italian" => Ok(PyLanguage::Italian),
+            "japanese" => Ok(PyLanguage::Japanese),
+            "korean" => Ok(PyLanguage::Korean),
+            "spanish" => Ok(PyLanguage::Spanish),
+            "portuguese" => Ok(PyLanguage::Portuguese),
             _ => Err(PyException::new_err(
                 "Unsupported string value for Language",
             )),
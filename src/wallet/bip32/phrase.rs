@@ -1,9 +1,91 @@
+use crate::crypto::keyfile;
+use crate::crypto::keystore;
+use crate::crypto::shamir;
+use crate::types::PyBinary;
 use crate::wallet::bip32::language::PyLanguage;
+use crate::wallet::keys::xprv::PyXPrv;
 use kaspa_bip32::{Error, Language, Mnemonic};
+use kaspa_utils::hex::FromHex;
+use pyo3::types::PyBytes;
 use pyo3::{exceptions::PyException, prelude::*};
 use pyo3_stub_gen::derive::*;
 use workflow_core::hex::ToHex;
 
+/// Map a raw entropy length in bytes to its BIP-39 word count, rejecting any
+/// length BIP-39 doesn't define (only 16/20/24/28/32-byte entropy produces a
+/// whole number of 11-bit words plus checksum).
+fn word_count_for_entropy_len(len: usize) -> PyResult<u32> {
+    match len {
+        16 => Ok(12),
+        20 => Ok(15),
+        24 => Ok(18),
+        28 => Ok(21),
+        32 => Ok(24),
+        other => Err(PyException::new_err(format!(
+            "entropy has an unsupported length: {other} bytes (expected 16, 20, 24, 28, or 32)"
+        ))),
+    }
+}
+
+/// Map a `Language` to the single byte `split_shares`/`combine_shares`
+/// prepend to the shared secret, so a Shamir share carries the wordlist
+/// language its mnemonic was split in.
+fn language_to_byte(language: &Language) -> u8 {
+    match language {
+        Language::English => 0,
+        Language::SimplifiedChinese => 1,
+        Language::TraditionalChinese => 2,
+        Language::Czech => 3,
+        Language::French => 4,
+        Language::Italian => 5,
+        Language::Japanese => 6,
+        Language::Korean => 7,
+        Language::Spanish => 8,
+        Language::Portuguese => 9,
+    }
+}
+
+/// Inverse of [`language_to_byte`].
+fn language_from_byte(byte: u8) -> PyResult<Language> {
+    match byte {
+        0 => Ok(Language::English),
+        1 => Ok(Language::SimplifiedChinese),
+        2 => Ok(Language::TraditionalChinese),
+        3 => Ok(Language::Czech),
+        4 => Ok(Language::French),
+        5 => Ok(Language::Italian),
+        6 => Ok(Language::Japanese),
+        7 => Ok(Language::Korean),
+        8 => Ok(Language::Spanish),
+        9 => Ok(Language::Portuguese),
+        other => Err(PyException::new_err(format!(
+            "share has an unrecognized language byte: {other}"
+        ))),
+    }
+}
+
+/// Build a `Mnemonic` whose phrase matches the given entropy exactly.
+///
+/// There is no direct "entropy in, phrase out" constructor on the underlying
+/// `Mnemonic`, so this seeds a placeholder phrase of the right word count for
+/// `language` and then overwrites its entropy, which recomputes the phrase
+/// (including the trailing checksum word) to match.
+fn mnemonic_from_entropy(entropy: &str, language: Language) -> PyResult<Mnemonic> {
+    let bytes = Vec::<u8>::from_hex(entropy)
+        .map_err(|err| PyException::new_err(format!("Invalid entropy hex: {err}")))?;
+    let word_count = word_count_for_entropy_len(bytes.len())?;
+
+    let mut inner = Mnemonic::random(
+        (word_count as usize)
+            .try_into()
+            .map_err(|err: Error| PyException::new_err(err.to_string()))?,
+        language,
+    )
+    .map_err(|err: Error| PyException::new_err(err.to_string()))?;
+    inner.set_entropy(entropy.to_string());
+    Ok(inner)
+}
+
 /// A BIP-39 mnemonic seed phrase.
 ///
 /// Mnemonic phrases (also called seed phrases or recovery phrases) are
@@ -11,7 +93,14 @@ use workflow_core::hex::ToHex;
 /// generation.
 #[gen_stub_pyclass]
 #[pyclass(name = "Mnemonic")]
-pub struct PyMnemonic(Mnemonic);
+pub struct PyMnemonic {
+    inner: Mnemonic,
+    /// The wordlist language this mnemonic's phrase is in, encoded via
+    /// `language_to_byte`. Threaded into `split_shares`'s Shamir shares so
+    /// `combine_shares` can reconstruct the phrase in its original language
+    /// instead of always assuming English.
+    language: u8,
+}
 
 #[gen_stub_pymethods]
 #[pymethods]
@@ -34,13 +123,12 @@ impl PyMnemonic {
         #[gen_stub(override_type(type_repr = "str | Language = Language.English"))]
         language: Option<PyLanguage>,
     ) -> PyResult<Self> {
-        let inner = Mnemonic::new(
-            phrase,
-            language.map(Language::from).unwrap_or(Language::English),
-        )
-        .map_err(|err| PyException::new_err(err.to_string()))?;
+        let language = language.map(Language::from).unwrap_or(Language::English);
+        let language_id = language_to_byte(&language);
+        let inner = Mnemonic::new(phrase, language)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
 
-        Ok(Self(inner))
+        Ok(Self { inner, language: language_id })
     }
 
     /// Validate a mnemonic phrase.
@@ -67,33 +155,39 @@ impl PyMnemonic {
 
     /// The entropy bytes as a hex string.
     ///
+    /// Always reflects whichever phrase is currently set, so `entropy` and
+    /// `phrase` can never drift out of sync with each other.
+    ///
     /// Returns:
     ///     str: The raw entropy in hexadecimal.
     #[getter]
     pub fn get_entropy(&self) -> String {
-        self.0.get_entropy()
+        self.inner.get_entropy()
     }
 
-    /// Set the entropy directly.
+    /// Set the entropy directly, recomputing the phrase (including its
+    /// checksum word) to match.
     ///
     /// Args:
-    ///     value: The entropy as a hex string (16 or 32 bytes).
+    ///     value: The entropy as a hex string (16, 20, 24, 28, or 32 bytes).
+    ///
+    /// Raises:
+    ///     Exception: If `value` is not valid hex or has an unsupported
+    ///         length.
     #[setter]
-    pub fn set_entropy(&mut self, value: &str) {
-        // let vec = Vec::<u8>::from_hex(entropy)
-        //     .unwrap_or_else(|err| panic!("invalid entropy `{entropy}`: {err}"));
-        // let len = vec.len();
-        // if len != 16 && len != 32 {
-        //     panic!("Invalid entropy: `{entropy}`")
-        // }
-        self.0.set_entropy(value.to_string());
-        // self.entropy = vec;
+    pub fn set_entropy(&mut self, value: &str) -> PyResult<()> {
+        let bytes = Vec::<u8>::from_hex(value)
+            .map_err(|err| PyException::new_err(format!("Invalid entropy hex: {err}")))?;
+        word_count_for_entropy_len(bytes.len())?;
+        self.inner.set_entropy(value.to_string());
+        Ok(())
     }
 
     /// Generate a random mnemonic phrase.
     ///
     /// Args:
     ///     word_count: Number of words (12, 15, 18, 21, or 24). Default: 24.
+    ///     language: Optional wordlist language (default: English).
     ///
     /// Returns:
     ///     Mnemonic: A new random mnemonic.
@@ -102,17 +196,49 @@ impl PyMnemonic {
     ///     Exception: If the word count is invalid.
     #[staticmethod]
     #[pyo3(name = "random")]
-    #[pyo3(signature = (word_count=None))]
-    pub fn create_random(word_count: Option<u32>) -> PyResult<Self> {
+    #[pyo3(signature = (word_count=None, language=None))]
+    pub fn create_random(
+        word_count: Option<u32>,
+        #[gen_stub(override_type(type_repr = "str | Language = Language.English"))]
+        language: Option<PyLanguage>,
+    ) -> PyResult<Self> {
         let word_count = word_count.unwrap_or(24) as usize;
+        let language = language.map(Language::from).unwrap_or(Language::English);
+        let language_id = language_to_byte(&language);
         let inner = Mnemonic::random(
             word_count
                 .try_into()
                 .map_err(|err: Error| PyException::new_err(err.to_string()))?,
-            Default::default(),
+            language,
         )
         .map_err(|err: Error| PyException::new_err(err.to_string()))?;
-        Ok(Self(inner))
+        Ok(Self { inner, language: language_id })
+    }
+
+    /// Create a mnemonic directly from raw entropy.
+    ///
+    /// Args:
+    ///     entropy: The entropy as a hex string (16, 20, 24, 28, or 32
+    ///         bytes), mapping respectively to 12/15/18/21/24 words.
+    ///     language: Optional wordlist language (default: English).
+    ///
+    /// Returns:
+    ///     Mnemonic: The mnemonic whose phrase encodes `entropy`.
+    ///
+    /// Raises:
+    ///     Exception: If `entropy` is not valid hex or has an unsupported
+    ///         length.
+    #[staticmethod]
+    #[pyo3(signature = (entropy, language=None))]
+    pub fn from_entropy(
+        entropy: &str,
+        #[gen_stub(override_type(type_repr = "str | Language = Language.English"))]
+        language: Option<PyLanguage>,
+    ) -> PyResult<Self> {
+        let language = language.map(Language::from).unwrap_or(Language::English);
+        let language_id = language_to_byte(&language);
+        let inner = mnemonic_from_entropy(entropy, language)?;
+        Ok(Self { inner, language: language_id })
     }
 
     /// The mnemonic phrase as a string.
@@ -121,8 +247,7 @@ impl PyMnemonic {
     ///     str: The space-separated word phrase.
     #[getter]
     pub fn get_phrase(&self) -> String {
-        self.0.phrase().to_string()
-        // self.phrase.clone()
+        self.inner.phrase().to_string()
     }
 
     /// Set the mnemonic phrase.
@@ -131,7 +256,7 @@ impl PyMnemonic {
     ///     value: The mnemonic phrase string.
     #[setter]
     pub fn set_phrase(&mut self, value: String) {
-        self.0.set_phrase(&value);
+        self.inner.set_phrase(&value);
     }
 
     /// Convert the mnemonic to a seed for key derivation.
@@ -149,6 +274,165 @@ impl PyMnemonic {
     #[pyo3(signature = (password=None))]
     pub fn create_seed(&self, password: Option<&str>) -> String {
         let password = password.unwrap_or_default();
-        self.0.to_seed(password).as_bytes().to_vec().to_hex()
+        self.inner.to_seed(password).as_bytes().to_vec().to_hex()
+    }
+
+    /// Derive the BIP-32 master extended private key for this mnemonic.
+    ///
+    /// Bridges a freshly generated or imported mnemonic straight into the
+    /// existing `XPrv`/`XPub` key-derivation API, so a new wallet can
+    /// immediately produce a watch-only `XPub` via `to_xprv().to_xpub()`.
+    ///
+    /// Args:
+    ///     password: Optional passphrase for additional security.
+    ///
+    /// Returns:
+    ///     XPrv: The master extended private key derived from the seed.
+    ///
+    /// Raises:
+    ///     Exception: If master key derivation fails.
+    #[pyo3(signature = (password=None))]
+    pub fn to_xprv(&self, password: Option<&str>) -> PyResult<PyXPrv> {
+        PyXPrv::try_new(&self.create_seed(password))
+    }
+
+    /// Split this mnemonic's entropy into `shares` Shamir shares, any
+    /// `threshold` of which can reconstruct it via `combine_shares`.
+    ///
+    /// Implements Shamir's secret sharing over GF(2^8): the shared secret is
+    /// this mnemonic's wordlist language byte followed by its entropy bytes,
+    /// each treated as the constant term of an independent degree-`threshold
+    /// - 1` polynomial with random coefficients, evaluated at `x = 1..=shares`.
+    /// Carrying the language alongside the entropy lets `combine_shares`
+    /// rebuild the exact original phrase instead of assuming English. Each
+    /// returned share is prefixed with its `x` index and suffixed with a
+    /// SHA-256 digest of the secret, so `combine_shares` can detect a bad
+    /// combination of shares.
+    ///
+    /// Args:
+    ///     threshold: The minimum number of shares required to reconstruct
+    ///         the mnemonic. Must be at least 2 and at most `shares`.
+    ///     shares: The total number of shares to produce.
+    ///
+    /// Returns:
+    ///     list[bytes]: The generated shares, in `x = 1..=shares` order.
+    ///
+    /// Raises:
+    ///     Exception: If `threshold` is less than 2 or greater than `shares`.
+    ///
+    /// Category: Wallet/Keys
+    pub fn split_shares<'py>(
+        &self,
+        py: Python<'py>,
+        threshold: u8,
+        shares: u8,
+    ) -> PyResult<Vec<Bound<'py, PyBytes>>> {
+        let entropy = Vec::<u8>::from_hex(&self.inner.get_entropy())
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let mut secret = Vec::with_capacity(1 + entropy.len());
+        secret.push(self.language);
+        secret.extend_from_slice(&entropy);
+        shamir::split(&secret, threshold, shares).map(|shares| {
+            shares.into_iter().map(|share| PyBytes::new(py, &share)).collect()
+        })
+    }
+
+    /// Reconstruct a mnemonic from shares produced by `split_shares`.
+    ///
+    /// Args:
+    ///     shares: At least `threshold` shares produced by `split_shares`,
+    ///         with distinct `x` indices.
+    ///
+    /// Returns:
+    ///     Mnemonic: The reconstructed mnemonic, in its original wordlist
+    ///         language.
+    ///
+    /// Raises:
+    ///     Exception: If fewer than 2 shares are given, the shares have
+    ///         inconsistent lengths or duplicate indices, the reconstructed
+    ///         secret fails its checksum, or it carries an unrecognized
+    ///         language byte.
+    #[staticmethod]
+    pub fn combine_shares(shares: Vec<PyBinary>) -> PyResult<Self> {
+        let shares: Vec<Vec<u8>> = shares.into_iter().map(|share| share.data).collect();
+        let secret = shamir::combine(&shares)?;
+        let (&language_id, entropy) = secret
+            .split_first()
+            .ok_or_else(|| PyException::new_err("combined secret is empty"))?;
+        let language = language_from_byte(language_id)?;
+        let inner = mnemonic_from_entropy(&entropy.to_hex(), language)?;
+        Ok(Self { inner, language: language_id })
+    }
+
+    /// Encrypt this mnemonic into a portable keystore blob.
+    ///
+    /// Derives a key from `password` via PBKDF2-HMAC-SHA512 with a random
+    /// salt, then encrypts the phrase with AES-256-GCM. The result is a
+    /// self-describing JSON envelope safe to persist at rest.
+    ///
+    /// Args:
+    ///     password: The password to encrypt with.
+    ///
+    /// Returns:
+    ///     str: The JSON-encoded keystore envelope.
+    ///
+    /// Raises:
+    ///     Exception: If encryption fails.
+    pub fn to_encrypted(&self, password: &str) -> PyResult<String> {
+        keystore::encrypt(self.get_phrase().as_bytes(), password)
+    }
+
+    /// Decrypt a keystore blob produced by `to_encrypted`.
+    ///
+    /// Args:
+    ///     blob: The JSON-encoded keystore envelope.
+    ///     password: The password it was encrypted with.
+    ///
+    /// Returns:
+    ///     Mnemonic: The decrypted mnemonic.
+    ///
+    /// Raises:
+    ///     Exception: If the envelope is malformed, `password` is wrong, or
+    ///         the decrypted phrase fails its checksum.
+    #[staticmethod]
+    pub fn from_encrypted(blob: &str, password: &str) -> PyResult<Self> {
+        let plaintext = keystore::decrypt(blob, password)?;
+        let phrase = String::from_utf8(plaintext)
+            .map_err(|err| PyException::new_err(format!("Decrypted keystore is not valid UTF-8: {err}")))?;
+        Self::constructor(&phrase, None)
+    }
+
+    /// Save this mnemonic to a file.
+    ///
+    /// Args:
+    ///     path: The file path to write to.
+    ///     password: Optional password; if given, the file is encrypted
+    ///         with the same format as `to_encrypted`. Otherwise the phrase
+    ///         is stored in plain text.
+    ///
+    /// Raises:
+    ///     Exception: If writing the file fails.
+    #[pyo3(signature = (path, password=None))]
+    pub fn write_to_file(&self, path: &str, password: Option<&str>) -> PyResult<()> {
+        keyfile::write_to_file(path, "Mnemonic", &self.get_phrase(), password)
+    }
+
+    /// Load a mnemonic previously saved with `write_to_file`.
+    ///
+    /// Args:
+    ///     path: The file path to read from.
+    ///     password: The password, if the file is encrypted.
+    ///
+    /// Returns:
+    ///     Mnemonic: The loaded mnemonic.
+    ///
+    /// Raises:
+    ///     Exception: If the file is missing, not a `Mnemonic` key file, or
+    ///         `password` is required/wrong.
+    #[staticmethod]
+    #[pyo3(signature = (path, password=None))]
+    pub fn read_from_file(path: &str, password: Option<&str>) -> PyResult<Self> {
+        let phrase = keyfile::read_from_file(path, "Mnemonic", password)?;
+        Self::constructor(&phrase, None)
     }
 }
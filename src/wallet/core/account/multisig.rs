@@ -0,0 +1,318 @@
+use kaspa_consensus_core::network::NetworkType;
+use kaspa_txscript::standard::{multisig_redeem_script, multisig_redeem_script_ecdsa};
+use kaspa_wallet_core::{
+    derivation::{create_address, WalletDerivationManagerTrait},
+    prelude::AccountKind,
+};
+use kaspa_wallet_keys::{
+    derivation::gen1::WalletDerivationManager, publickey::PublicKey, xpub::XPub,
+};
+use pyo3::{exceptions::PyException, prelude::*};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use workflow_core::hex::ToHex;
+
+use crate::{
+    address::PyAddress, consensus::core::network::PyNetworkType,
+    wallet::core::account::kind::PyAccountKind,
+};
+
+/// An extended-public-key based multisig account.
+///
+/// Mirrors kaspad's `libkaspawallet.Address` derivation: each cosigner's
+/// extended public key derives its child key at the same BIP32 index, and
+/// the resulting public keys are fed into multisig address construction.
+/// This lets a wallet derive whole batches of receive/change addresses for
+/// a multisig account without assembling the key list by hand for each one.
+///
+/// Category: Wallet/Core
+#[gen_stub_pyclass]
+#[pyclass(name = "MultisigAccount")]
+#[derive(Clone)]
+pub struct PyMultisigAccount {
+    cosigners: Vec<WalletDerivationManager>,
+    minimum_signatures: usize,
+    network_type: NetworkType,
+    ecdsa: bool,
+    account_kind: Option<AccountKind>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyMultisigAccount {
+    /// Create a multisig account from a set of cosigner extended public keys.
+    ///
+    /// Args:
+    ///     xpubs: The cosigners' extended public keys (xpub/kpub format), in a
+    ///         fixed, agreed-upon order.
+    ///     minimum_signatures: The minimum number of signatures required to spend.
+    ///     network_type: The network type for address encoding.
+    ///     ecdsa: Use ECDSA signatures instead of Schnorr (default: False).
+    ///     account_kind: Optional account kind for derivation.
+    ///
+    /// Returns:
+    ///     MultisigAccount: A new MultisigAccount instance.
+    ///
+    /// Raises:
+    ///     Exception: If any xpub is invalid.
+    #[new]
+    #[pyo3(signature = (xpubs, minimum_signatures, network_type, ecdsa=Some(false), account_kind=None))]
+    pub fn try_new(
+        xpubs: Vec<String>,
+        minimum_signatures: usize,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
+        ecdsa: Option<bool>,
+        account_kind: Option<PyAccountKind>,
+    ) -> PyResult<Self> {
+        let cosigners = xpubs
+            .iter()
+            .enumerate()
+            .map(|(cosigner_index, xpub)| {
+                let xpub =
+                    XPub::try_new(xpub).map_err(|err| PyException::new_err(err.to_string()))?;
+                WalletDerivationManager::from_extended_public_key(
+                    xpub.inner().clone(),
+                    Some(cosigner_index as u32),
+                )
+                .map_err(|err| PyException::new_err(err.to_string()))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        Ok(Self {
+            cosigners,
+            minimum_signatures,
+            network_type: NetworkType::from(network_type),
+            ecdsa: ecdsa.unwrap_or(false),
+            account_kind: account_kind.map(AccountKind::from),
+        })
+    }
+
+    /// The number of cosigners in this multisig account.
+    ///
+    /// Returns:
+    ///     int: The cosigner count.
+    #[getter]
+    pub fn get_cosigner_count(&self) -> usize {
+        self.cosigners.len()
+    }
+
+    /// Derive a single multisig receive (external) address.
+    ///
+    /// Args:
+    ///     index: The address index.
+    ///
+    /// Returns:
+    ///     Address: The derived multisig address.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    pub fn receive_address(&self, index: u32) -> PyResult<PyAddress> {
+        self.derive_address(index, false)
+    }
+
+    /// Derive a batch of multisig receive (external) addresses over an index range.
+    ///
+    /// Args:
+    ///     start: Start index (inclusive).
+    ///     end: End index (exclusive).
+    ///
+    /// Returns:
+    ///     list[Address]: The derived multisig addresses.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    pub fn receive_addresses(&self, mut start: u32, mut end: u32) -> PyResult<Vec<PyAddress>> {
+        if start > end {
+            (start, end) = (end, start);
+        }
+        (start..end).map(|index| self.derive_address(index, false)).collect()
+    }
+
+    /// Derive a single multisig change (internal) address.
+    ///
+    /// Args:
+    ///     index: The address index.
+    ///
+    /// Returns:
+    ///     Address: The derived multisig address.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    pub fn change_address(&self, index: u32) -> PyResult<PyAddress> {
+        self.derive_address(index, true)
+    }
+
+    /// Derive a batch of multisig change (internal) addresses over an index range.
+    ///
+    /// Args:
+    ///     start: Start index (inclusive).
+    ///     end: End index (exclusive).
+    ///
+    /// Returns:
+    ///     list[Address]: The derived multisig addresses.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    pub fn change_addresses(&self, mut start: u32, mut end: u32) -> PyResult<Vec<PyAddress>> {
+        if start > end {
+            (start, end) = (end, start);
+        }
+        (start..end).map(|index| self.derive_address(index, true)).collect()
+    }
+
+    /// Derive the multisig redeem script backing a receive (external) address.
+    ///
+    /// Args:
+    ///     index: The address index.
+    ///
+    /// Returns:
+    ///     str: The redeem script as hex.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    pub fn receive_redeem_script(&self, index: u32) -> PyResult<String> {
+        self.derive_redeem_script(index, false)
+    }
+
+    /// Derive multisig redeem scripts over a range of receive (external) indexes.
+    ///
+    /// Args:
+    ///     start: Start index (inclusive).
+    ///     end: End index (exclusive).
+    ///
+    /// Returns:
+    ///     list[str]: The redeem scripts as hex, in index order.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    pub fn receive_redeem_scripts(&self, mut start: u32, mut end: u32) -> PyResult<Vec<String>> {
+        if start > end {
+            (start, end) = (end, start);
+        }
+        (start..end).map(|index| self.derive_redeem_script(index, false)).collect()
+    }
+
+    /// Derive the multisig redeem script backing a change (internal) address.
+    ///
+    /// Args:
+    ///     index: The address index.
+    ///
+    /// Returns:
+    ///     str: The redeem script as hex.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    pub fn change_redeem_script(&self, index: u32) -> PyResult<String> {
+        self.derive_redeem_script(index, true)
+    }
+
+    /// Derive multisig redeem scripts over a range of change (internal) indexes.
+    ///
+    /// Args:
+    ///     start: Start index (inclusive).
+    ///     end: End index (exclusive).
+    ///
+    /// Returns:
+    ///     list[str]: The redeem scripts as hex, in index order.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    pub fn change_redeem_scripts(&self, mut start: u32, mut end: u32) -> PyResult<Vec<String>> {
+        if start > end {
+            (start, end) = (end, start);
+        }
+        (start..end).map(|index| self.derive_redeem_script(index, true)).collect()
+    }
+}
+
+impl PyMultisigAccount {
+    /// Derive each cosigner's pubkey at `index`/`change`, then sort them
+    /// lexicographically (BIP67-style) by their script-encoded bytes so
+    /// every cosigner assembles an identical key order regardless of xpub
+    /// input order. Both `derive_address` (via `create_address`) and
+    /// `derive_redeem_script` (via the native `multisig_redeem_script*`
+    /// helpers) must derive from this same sorted list, or the address and
+    /// its supposed redeem script would lock/unlock different scripts.
+    fn sorted_pubkeys(&self, index: u32, change: bool) -> PyResult<Vec<PublicKey>> {
+        let mut keys = self
+            .cosigners
+            .iter()
+            .map(|cosigner| {
+                let manager = if change {
+                    cosigner.change_pubkey_manager()
+                } else {
+                    cosigner.receive_pubkey_manager()
+                };
+                manager
+                    .derive_pubkey(index)
+                    .map(PublicKey::from)
+                    .map_err(|err| PyException::new_err(err.to_string()))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        if self.ecdsa {
+            keys.sort_unstable_by_key(|key| key.public_key.map(|pk| pk.serialize()));
+        } else {
+            keys.sort_unstable_by_key(|key| key.xonly_public_key.inner.serialize());
+        }
+        Ok(keys)
+    }
+
+    fn derive_address(&self, index: u32, change: bool) -> PyResult<PyAddress> {
+        let keys = self.sorted_pubkeys(index, change)?;
+
+        let address = create_address(
+            self.minimum_signatures,
+            keys,
+            self.network_type,
+            self.ecdsa,
+            self.account_kind.clone(),
+        )
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+
+        Ok(address.into())
+    }
+
+    /// Build the multisig redeem script for a given index and chain, from
+    /// the same sorted cosigner pubkeys `derive_address` uses, via the
+    /// native `multisig_redeem_script`/`multisig_redeem_script_ecdsa`
+    /// helpers - the same canonical script builders `create_address` and
+    /// `create_multisig_address_with_script` rely on - so the script
+    /// returned here always matches the address's actual locking script.
+    fn derive_redeem_script(&self, index: u32, change: bool) -> PyResult<String> {
+        let n = self.cosigners.len();
+        if n == 0 || n > 16 {
+            return Err(PyException::new_err(
+                "multisig requires between 1 and 16 cosigners",
+            ));
+        }
+        if self.minimum_signatures < 1 || self.minimum_signatures > n {
+            return Err(PyException::new_err(format!(
+                "minimum_signatures ({}) must be between 1 and the number of cosigners ({n})",
+                self.minimum_signatures
+            )));
+        }
+
+        let keys = self.sorted_pubkeys(index, change)?;
+
+        let redeem_script = if self.ecdsa {
+            let pubkeys = keys
+                .into_iter()
+                .map(|key| {
+                    key.public_key.map(|pk| pk.serialize()).ok_or_else(|| {
+                        PyException::new_err("ECDSA multisig requires full (non x-only) public keys")
+                    })
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            multisig_redeem_script_ecdsa(pubkeys.iter(), self.minimum_signatures)
+        } else {
+            let pubkeys = keys
+                .into_iter()
+                .map(|key| key.xonly_public_key.inner.serialize())
+                .collect::<Vec<_>>();
+            multisig_redeem_script(pubkeys.iter(), self.minimum_signatures)
+        }
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+
+        Ok(redeem_script.as_ref().to_vec().to_hex())
+    }
+}
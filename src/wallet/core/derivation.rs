@@ -1,15 +1,90 @@
 use kaspa_consensus_core::network::NetworkType;
+use kaspa_txscript::standard::{
+    extract_script_pub_key_address, multisig_redeem_script, multisig_redeem_script_ecdsa,
+    pay_to_script_hash_script,
+};
 use kaspa_wallet_core::{derivation::create_address, prelude::AccountKind};
 use kaspa_wallet_keys::publickey::PublicKey;
 use pyo3::{exceptions::PyException, prelude::*};
-use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyfunction, gen_stub_pymethods};
+use workflow_core::hex::ToHex;
 
 use crate::{
     address::PyAddress,
-    consensus::core::network::PyNetworkType,
+    consensus::core::{network::PyNetworkType, script_public_key::PyScriptPublicKey},
     wallet::{core::account::kind::PyAccountKind, keys::publickey::PyPublicKey},
 };
 
+/// The maximum number of cosigners a multisig address can be built from.
+///
+/// Mirrors the protocol's standard-transaction sigop limit for P2SH inputs;
+/// beyond this, a redeem script can no longer be relayed as standard.
+const MAX_MULTISIG_COSIGNERS: usize = 20;
+
+/// Validate a multisig configuration and collect non-fatal warnings about
+/// suspicious-but-allowed setups, matching Bitcoin Core's `createmultisig` checks.
+fn validate_multisig_keys(
+    minimum_signatures: usize,
+    keys: &[PyPublicKey],
+) -> PyResult<Vec<String>> {
+    if minimum_signatures < 1 {
+        return Err(PyException::new_err(
+            "`minimum_signatures` must be at least 1",
+        ));
+    }
+    if keys.len() < minimum_signatures {
+        return Err(PyException::new_err(format!(
+            "`minimum_signatures` ({minimum_signatures}) cannot exceed the number of keys ({})",
+            keys.len()
+        )));
+    }
+    if keys.len() > MAX_MULTISIG_COSIGNERS {
+        return Err(PyException::new_err(format!(
+            "multisig address cannot have more than {MAX_MULTISIG_COSIGNERS} cosigners, got {}",
+            keys.len()
+        )));
+    }
+
+    let mut warnings = Vec::new();
+    if minimum_signatures == 1 {
+        warnings.push(
+            "minimum_signatures is 1: any single cosigner can spend, this is not really multisig"
+                .to_string(),
+        );
+    }
+    if minimum_signatures == keys.len() {
+        warnings.push(
+            "minimum_signatures equals the number of keys: there is no redundancy if a cosigner loses their key"
+                .to_string(),
+        );
+    }
+
+    let serialized: Vec<String> = keys.iter().map(|pk| pk.to_string_impl()).collect();
+    let mut seen = std::collections::HashSet::new();
+    if serialized.iter().any(|key| !seen.insert(key)) {
+        warnings.push("the key list contains duplicate public keys".to_string());
+    }
+
+    let has_full = keys.iter().any(|pk| pk.0.public_key.is_some());
+    let has_xonly_only = keys.iter().any(|pk| pk.0.public_key.is_none());
+    if has_full && has_xonly_only {
+        warnings.push(
+            "the key list mixes full public keys with x-only public keys".to_string(),
+        );
+    }
+
+    Ok(warnings)
+}
+
+/// Sort keys lexicographically by their serialized byte representation (as
+/// used in the redeem script), so that all cosigners deterministically arrive
+/// at the same key order, and therefore the same address, regardless of the
+/// order they were supplied in.
+fn sort_keys_lexicographically(mut keys: Vec<PyPublicKey>) -> Vec<PyPublicKey> {
+    keys.sort_by(|a, b| a.to_string_impl().cmp(&b.to_string_impl()));
+    keys
+}
+
 /// Create a multisig address from multiple public keys.
 ///
 /// Args:
@@ -18,35 +93,168 @@ use crate::{
 ///     network_type: The network type for address encoding.
 ///     ecdsa: Use ECDSA signatures instead of Schnorr (default: False).
 ///     account_kind: Optional account kind for derivation.
+///     sort_keys: Lexicographically sort keys by their serialized byte
+///         representation before building the script, so cosigners listing
+///         their keys in a different order still derive the same address
+///         (default: True). Set to False to keep a fixed, caller-supplied
+///         order for compatibility with existing scripts.
 ///
 /// Returns:
-///     Address: The multisig address.
+///     tuple[Address, list[str]]: The multisig address and a list of non-fatal
+///     warnings about suspicious-but-allowed configurations (e.g. duplicate keys).
 ///
 /// Raises:
-///     Exception: If address creation fails.
+///     Exception: If the key count is invalid or address creation fails.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "create_multisig_address")]
-#[pyo3(signature = (minimum_signatures, keys, network_type, ecdsa=Some(false), account_kind=None))]
+#[pyo3(signature = (minimum_signatures, keys, network_type, ecdsa=Some(false), account_kind=None, sort_keys=Some(true)))]
 pub fn py_create_multisig_address(
     minimum_signatures: usize,
     keys: Vec<PyPublicKey>,
     #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
     ecdsa: Option<bool>,
     account_kind: Option<PyAccountKind>,
-) -> PyResult<PyAddress> {
-    let keys = keys
+    sort_keys: Option<bool>,
+) -> PyResult<(PyAddress, Vec<String>)> {
+    let warnings = validate_multisig_keys(minimum_signatures, &keys)?;
+    let keys = if sort_keys.unwrap_or(true) {
+        sort_keys_lexicographically(keys)
+    } else {
+        keys
+    };
+
+    let native_keys = keys
         .into_iter()
         .map(|pk| PublicKey::from(pk).try_into())
         .collect::<Result<Vec<_>, kaspa_wallet_keys::error::Error>>()
         .map_err(|err| PyException::new_err(err.to_string()))?;
-    Ok(create_address(
+    let address = create_address(
         minimum_signatures,
-        keys,
+        native_keys,
         NetworkType::from(network_type).into(),
         ecdsa.unwrap_or(false),
         account_kind.map(AccountKind::from),
     )
-    .map_err(|err| PyException::new_err(err.to_string()))?
-    .into())
+    .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    Ok((address.into(), warnings))
+}
+
+/// The result of creating a multisig address, carrying the underlying redeem
+/// script alongside the address itself.
+///
+/// Mirrors Bitcoin Core's `createmultisig`, which returns both `address` and
+/// `redeemScript`, so that callers building PSKT / partial-signing flows can
+/// reconstruct the script without re-deriving it from the original public keys.
+#[gen_stub_pyclass]
+#[pyclass(name = "MultisigAddressResult")]
+#[derive(Clone)]
+pub struct PyMultisigAddressResult {
+    address: PyAddress,
+    redeem_script: String,
+    script_public_key: PyScriptPublicKey,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyMultisigAddressResult {
+    /// The multisig address.
+    ///
+    /// Returns:
+    ///     Address: The multisig address.
+    #[getter]
+    pub fn get_address(&self) -> PyAddress {
+        self.address.clone()
+    }
+
+    /// The hex-encoded multisig redeem script.
+    ///
+    /// Returns:
+    ///     str: The redeem script as a hex string.
+    #[getter]
+    pub fn get_redeem_script(&self) -> String {
+        self.redeem_script.clone()
+    }
+
+    /// The P2SH script public key (and version) locking funds sent to the address.
+    ///
+    /// Returns:
+    ///     ScriptPublicKey: The pay-to-script-hash script public key.
+    #[getter]
+    pub fn get_script_public_key(&self) -> PyScriptPublicKey {
+        self.script_public_key.clone()
+    }
+}
+
+/// Create a multisig address together with its redeem script and P2SH script public key.
+///
+/// Args:
+///     minimum_signatures: The minimum number of signatures required to spend.
+///     keys: List of public keys for the multisig.
+///     network_type: The network type for address encoding.
+///     ecdsa: Use ECDSA signatures instead of Schnorr (default: False).
+///     sort_keys: Lexicographically sort keys before building the script
+///         (default: True). See [`py_create_multisig_address`].
+///
+/// Returns:
+///     MultisigAddressResult: The address, its redeem script and script public key.
+///
+/// Raises:
+///     Exception: If script construction or address derivation fails.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "create_multisig_address_with_script")]
+#[pyo3(signature = (minimum_signatures, keys, network_type, ecdsa=Some(false), sort_keys=Some(true)))]
+pub fn py_create_multisig_address_with_script(
+    minimum_signatures: usize,
+    keys: Vec<PyPublicKey>,
+    #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
+    ecdsa: Option<bool>,
+    sort_keys: Option<bool>,
+) -> PyResult<PyMultisigAddressResult> {
+    validate_multisig_keys(minimum_signatures, &keys)?;
+    let keys = if sort_keys.unwrap_or(true) {
+        sort_keys_lexicographically(keys)
+    } else {
+        keys
+    };
+
+    let ecdsa = ecdsa.unwrap_or(false);
+    let network_type = NetworkType::from(network_type);
+
+    let redeem_script = if ecdsa {
+        let pub_keys = keys
+            .into_iter()
+            .map(|pk| {
+                PublicKey::from(pk)
+                    .public_key
+                    .map(|key| key.serialize())
+                    .ok_or_else(|| {
+                        PyException::new_err(
+                            "ECDSA multisig requires full (non x-only) public keys",
+                        )
+                    })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        multisig_redeem_script_ecdsa(pub_keys.iter(), minimum_signatures)
+    } else {
+        let pub_keys = keys
+            .into_iter()
+            .map(|pk| PublicKey::from(pk).xonly_public_key.serialize())
+            .collect::<Vec<_>>();
+        multisig_redeem_script(pub_keys.iter(), minimum_signatures)
+    }
+    .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    let script_public_key: PyScriptPublicKey = pay_to_script_hash_script(&redeem_script).into();
+    let address =
+        extract_script_pub_key_address(&script_public_key.clone().into(), network_type.into())
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    Ok(PyMultisigAddressResult {
+        address: address.into(),
+        redeem_script: redeem_script.as_ref().to_vec().to_hex(),
+        script_public_key,
+    })
 }
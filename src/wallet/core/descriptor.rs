@@ -0,0 +1,225 @@
+//! Canonical, checksummed serialization of wallet descriptors (BIP-380 style).
+
+use kaspa_consensus_core::network::NetworkType;
+use pyo3::{exceptions::PyException, prelude::*};
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use std::str::FromStr;
+
+use crate::{
+    consensus::core::network::PyNetworkType,
+    wallet::{
+        core::{account::kind::PyAccountKind, derivation::PyMultisigAddressResult},
+        keys::publickey::PyPublicKey,
+    },
+};
+
+use super::derivation::py_create_multisig_address_with_script;
+
+/// Characters that may appear in a descriptor, indexed by position for the checksum.
+const INPUT_CHARSET: &str = "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ABCDEFGHijklmnopqrstuvwxyz#\"\\ ";
+
+/// Bech32 charset used to render the 8-character checksum suffix.
+const CHECKSUM_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+fn polymod(mut c: u64, val: u64) -> u64 {
+    let c0 = c >> 35;
+    c = ((c & 0x7_ffff_ffff) << 5) ^ val;
+    if c0 & 1 != 0 {
+        c ^= 0xf5dee51989;
+    }
+    if c0 & 2 != 0 {
+        c ^= 0xa9fdca3312;
+    }
+    if c0 & 4 != 0 {
+        c ^= 0x1bab10e32d;
+    }
+    if c0 & 8 != 0 {
+        c ^= 0x3706b1677a;
+    }
+    if c0 & 16 != 0 {
+        c ^= 0x644d626ffd;
+    }
+    c
+}
+
+/// Compute the 8-character BIP-380 descriptor checksum for `descriptor`.
+///
+/// Shared by every descriptor producer in this crate (e.g.
+/// `PyPublicKeyGenerator::to_descriptor`) so there is exactly one
+/// implementation of `INPUT_CHARSET` and the polymod feed order to keep in
+/// sync with the BIP-380 reference.
+pub(crate) fn descriptor_checksum(descriptor: &str) -> PyResult<String> {
+    let mut c: u64 = 1;
+    let mut cls: u64 = 0;
+    let mut cls_count = 0;
+
+    for ch in descriptor.chars() {
+        let pos = INPUT_CHARSET
+            .find(ch)
+            .ok_or_else(|| PyException::new_err(format!("Invalid descriptor character: {ch}")))?
+            as u64;
+        c = polymod(c, pos & 31);
+        cls = cls * 3 + (pos >> 5);
+        cls_count += 1;
+        if cls_count == 3 {
+            c = polymod(c, cls);
+            cls = 0;
+            cls_count = 0;
+        }
+    }
+    if cls_count > 0 {
+        c = polymod(c, cls);
+    }
+    for _ in 0..8 {
+        c = polymod(c, 0);
+    }
+    c ^= 1;
+
+    let checksum_chars = CHECKSUM_CHARSET.as_bytes();
+    let checksum: String = (0..8)
+        .map(|j| checksum_chars[((c >> (5 * (7 - j))) & 31) as usize] as char)
+        .collect();
+    Ok(checksum)
+}
+
+/// Append a `#`-delimited checksum to a descriptor string.
+fn append_checksum(descriptor: &str) -> PyResult<String> {
+    Ok(format!("{descriptor}#{}", descriptor_checksum(descriptor)?))
+}
+
+/// Split a checksummed descriptor and verify the checksum matches.
+fn verify_checksum(descriptor: &str) -> PyResult<String> {
+    let (body, checksum) = descriptor
+        .rsplit_once('#')
+        .ok_or_else(|| PyException::new_err("Descriptor is missing a '#' checksum suffix"))?;
+    let expected = descriptor_checksum(body)?;
+    if checksum != expected {
+        return Err(PyException::new_err(format!(
+            "Descriptor checksum mismatch: expected '{expected}', got '{checksum}'"
+        )));
+    }
+    Ok(body.to_string())
+}
+
+/// Serialize a multisig configuration into a single, portable, checksummed string.
+///
+/// Args:
+///     minimum_signatures: The minimum number of signatures required to spend.
+///     keys: The ordered list of cosigner public keys.
+///     network_type: The network type for address encoding.
+///     ecdsa: Use ECDSA signatures instead of Schnorr (default: False).
+///     account_kind: Optional account kind for derivation.
+///
+/// Returns:
+///     str: A self-describing `multi(...)#checksum` descriptor string.
+///
+/// Raises:
+///     Exception: If any field cannot be encoded.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "multisig_descriptor")]
+#[pyo3(signature = (minimum_signatures, keys, network_type, ecdsa=Some(false), account_kind=None))]
+pub fn py_multisig_descriptor(
+    minimum_signatures: usize,
+    keys: Vec<PyPublicKey>,
+    #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
+    ecdsa: Option<bool>,
+    account_kind: Option<PyAccountKind>,
+) -> PyResult<String> {
+    let ecdsa = ecdsa.unwrap_or(false);
+    let network_type = NetworkType::from(network_type);
+    let account_kind = account_kind
+        .map(|kind| kind.py_to_string())
+        .unwrap_or_default();
+    let keys = keys
+        .iter()
+        .map(|pk| pk.to_string_impl())
+        .collect::<Vec<_>>()
+        .join("/");
+
+    let body = format!("multi({minimum_signatures},{ecdsa},{network_type},{account_kind},{keys})");
+    append_checksum(&body)
+}
+
+/// Parse and verify a multisig descriptor string produced by [`py_multisig_descriptor`].
+///
+/// Args:
+///     descriptor: The `multi(...)#checksum` descriptor string.
+///
+/// Returns:
+///     MultisigAddressResult: The reconstructed address, redeem script and script public key.
+///
+/// Raises:
+///     Exception: If the checksum does not match or the descriptor is malformed.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "parse_multisig_descriptor")]
+pub fn py_parse_multisig_descriptor(descriptor: &str) -> PyResult<PyMultisigAddressResult> {
+    let body = verify_checksum(descriptor)?;
+
+    let inner = body
+        .strip_prefix("multi(")
+        .and_then(|s| s.strip_suffix(')'))
+        .ok_or_else(|| PyException::new_err("Descriptor is not a `multi(...)` expression"))?;
+
+    let fields: Vec<&str> = inner.splitn(5, ',').collect();
+    let [minimum_signatures, ecdsa, network_type, account_kind, keys] = fields[..] else {
+        return Err(PyException::new_err(
+            "Expected `multi(minimum_signatures,ecdsa,network,account_kind,keys)`",
+        ));
+    };
+
+    let minimum_signatures: usize = minimum_signatures
+        .parse()
+        .map_err(|_| PyException::new_err("Invalid minimum_signatures in descriptor"))?;
+    let ecdsa: bool = ecdsa
+        .parse()
+        .map_err(|_| PyException::new_err("Invalid ecdsa flag in descriptor"))?;
+    let network_type = NetworkType::from_str(network_type)
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    let keys = keys
+        .split('/')
+        .map(PyPublicKey::try_new)
+        .collect::<PyResult<Vec<_>>>()?;
+    let _ = account_kind;
+
+    py_create_multisig_address_with_script(
+        minimum_signatures,
+        keys,
+        network_type.into(),
+        Some(ecdsa),
+        Some(false),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_round_trips_through_append_and_verify() {
+        let body = "pkh(xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8/0/*)";
+        let checksummed = append_checksum(body).unwrap();
+        assert!(checksummed.starts_with(body));
+        assert_eq!(verify_checksum(&checksummed).unwrap(), body);
+    }
+
+    #[test]
+    fn verify_checksum_rejects_a_tampered_body() {
+        let body = "pkh(xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8/0/*)";
+        let checksummed = append_checksum(body).unwrap();
+        let mutated_checksum = {
+            let (body_part, checksum_part) = checksummed.rsplit_once('#').unwrap();
+            let mut chars: Vec<char> = checksum_part.chars().collect();
+            let last = chars.len() - 1;
+            chars[last] = if chars[last] == 'q' { 'p' } else { 'q' };
+            format!("{body_part}#{}", chars.into_iter().collect::<String>())
+        };
+        assert!(verify_checksum(&mutated_checksum).is_err());
+    }
+
+    #[test]
+    fn descriptor_checksum_rejects_characters_outside_the_charset() {
+        assert!(descriptor_checksum("pkh(\u{1f600})").is_err());
+    }
+}
@@ -1,5 +1,10 @@
-use crate::wallet::keys::{privatekey::PyPrivateKey, publickey::PyPublicKey};
+use crate::{
+    address::PyAddress,
+    wallet::keys::{privatekey::PyPrivateKey, publickey::PyPublicKey},
+};
+use kaspa_addresses::Version;
 // use kaspa_wallet_core::imports::*;
+use kaspa_consensus_core::network::NetworkType;
 use kaspa_wallet_core::message::*;
 use pyo3::{exceptions::PyException, prelude::*};
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
@@ -72,3 +77,45 @@ pub fn py_verify_message(
     )
     .is_ok())
 }
+
+/// Verify a message signature and that the signer's public key resolves to a claimed address.
+///
+/// Args:
+///     message: The original message.
+///     signature: The signature hex string.
+///     public_key: The public key to verify against.
+///     address: The address the signer claims to own.
+///
+/// Returns:
+///     bool: True if the signature is valid AND `public_key` derives to `address`
+///     (matching both version and payload).
+///
+/// Raises:
+///     Exception: If the signature format is invalid, or the address's network
+///     prefix does not correspond to a known network type.
+///
+/// Category: Wallet/Core
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "verify_message_with_address")]
+pub fn py_verify_message_with_address(
+    message: String,
+    signature: String,
+    public_key: PyPublicKey,
+    address: PyAddress,
+) -> PyResult<bool> {
+    if !py_verify_message(message, signature, public_key.clone())? {
+        return Ok(false);
+    }
+
+    let network_type = NetworkType::try_from(address.0.prefix)
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    let derived = match address.0.version {
+        Version::PubKeyECDSA => public_key.0.to_address_ecdsa(network_type),
+        _ => public_key.0.to_address(network_type),
+    }
+    .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    Ok(derived == address.0)
+}
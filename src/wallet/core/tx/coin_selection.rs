@@ -0,0 +1,288 @@
+use crate::consensus::client::outpoint::PyTransactionOutpoint;
+use crate::consensus::core::network::PyNetworkId;
+
+use super::super::imports::*;
+use kaspa_consensus_core::config::params::Params;
+use kaspa_consensus_core::mass::{UtxoCell, calc_storage_mass};
+use kaspa_wallet_core::tx::mass::MassCalculator;
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyfunction, gen_stub_pymethods};
+
+/// Dust-sized fallback used to size the implied change output before the
+/// real output amount is known, matching the rough `cost_of_change` estimate
+/// used while searching for a subset.
+const COST_OF_CHANGE: u64 = 10_000;
+
+/// Rough compute-mass contribution of a single input, used only to rank
+/// candidate subsets before a concrete transaction exists to mass-calculate
+/// directly. Mirrors the per-input cost of a standard schnorr P2PK spend.
+const COMPUTE_MASS_PER_INPUT: u64 = 1_000;
+/// Rough compute-mass contribution of a single output.
+const COMPUTE_MASS_PER_OUTPUT: u64 = 300;
+/// Rough fixed compute-mass overhead per transaction (version, lock time, etc).
+const COMPUTE_MASS_OVERHEAD: u64 = 200;
+
+/// Maximum number of candidate UTXOs considered via exhaustive
+/// branch-and-bound search. Beyond this, the 2^n search space and the
+/// recursion depth both become impractical - a wallet's UTXO set can
+/// easily hold this many same-valued UTXOs due to churn, which defeats the
+/// `sum`/`remaining` prunes in `branch_and_bound`. `select_utxos` falls
+/// back to `greedy_select` over the full candidate set in that case.
+const BNB_MAX_CANDIDATES: usize = 500;
+
+/// Hard cap on the number of `branch_and_bound` recursive calls, mirroring
+/// Bitcoin Core's BnB `TOTAL_TRIES` budget. The `sum`/`remaining` prunes
+/// don't bound worst-case behavior (e.g. many same-valued small UTXOs), so
+/// this stops the search once it's spent "enough" effort instead of
+/// exhausting the full exponential search space; `select_utxos` falls back
+/// to `greedy_select` if the budget runs out before a feasible subset is found.
+const BNB_MAX_TRIES: u32 = 100_000;
+
+/// Estimate the compute mass of a transaction spending `input_count` inputs
+/// and producing `output_count` outputs, without requiring a concrete
+/// transaction to calculate mass against.
+pub(crate) fn estimate_compute_mass(input_count: usize, output_count: usize) -> u64 {
+    COMPUTE_MASS_OVERHEAD
+        + input_count as u64 * COMPUTE_MASS_PER_INPUT
+        + output_count as u64 * COMPUTE_MASS_PER_OUTPUT
+}
+
+/// A UTXO coin-selection result from `select_utxos`.
+///
+/// Describes the chosen subset of candidate UTXOs, whether a change output
+/// is required to return the overshoot, and the storage/compute mass
+/// implied by spending that subset.
+#[gen_stub_pyclass]
+#[pyclass(name = "Selection")]
+#[derive(Clone)]
+pub struct PySelection {
+    /// The outpoints of the selected UTXOs, in selection order.
+    #[pyo3(get)]
+    outpoints: Vec<PyTransactionOutpoint>,
+    /// The total value of the selected UTXOs, in sompi.
+    #[pyo3(get)]
+    total_value: u64,
+    /// Whether spending this subset requires a change output.
+    #[pyo3(get)]
+    needs_change: bool,
+    /// The storage mass implied by this subset (plus a change output, if any).
+    #[pyo3(get)]
+    storage_mass: u64,
+    /// The compute mass implied by a transaction with this many inputs/outputs.
+    #[pyo3(get)]
+    compute_mass: u64,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PySelection {
+    fn __repr__(&self) -> String {
+        format!(
+            "Selection(outpoints={}, total_value={}, needs_change={}, storage_mass={}, compute_mass={})",
+            self.outpoints.len(), self.total_value, self.needs_change, self.storage_mass, self.compute_mass
+        )
+    }
+}
+
+/// Select UTXOs for a target amount via a storage-mass-aware branch-and-bound
+/// search.
+///
+/// Candidates are sorted by value descending, then explored by recursively
+/// including/excluding the next candidate while tracking the running sum.
+/// A subset is feasible if its sum falls in
+/// `[target + estimated_fee, target + estimated_fee + cost_of_change]`;
+/// branches whose sum already exceeds the upper bound, or whose remaining
+/// candidates cannot reach the target, are pruned. Among feasible subsets,
+/// the one minimizing total mass (storage + compute) is returned, with a
+/// strong preference for exact matches that need no change output.
+///
+/// Args:
+///     network_id: The network identifier (used for mass calculation).
+///     utxos: Candidate UTXOs as `(outpoint, value)` pairs.
+///     target: The amount to cover, in sompi.
+///     fee_rate: The fee rate multiplier applied to the estimated fee
+///         (default: 1.0).
+///
+/// Returns:
+///     Selection: The chosen subset and its implied mass, or a Selection
+///     with no outpoints if no feasible subset exists.
+///
+/// Raises:
+///     Exception: If `target` is zero or `utxos` is empty.
+///
+/// Category: Wallet/Transactions
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "select_utxos")]
+#[pyo3(signature = (network_id, utxos, target, fee_rate=1.0))]
+pub fn py_select_utxos(
+    network_id: PyNetworkId,
+    utxos: Vec<(PyTransactionOutpoint, u64)>,
+    target: u64,
+    fee_rate: f64,
+) -> PyResult<PySelection> {
+    if target == 0 {
+        return Err(PyException::new_err("`target` must be greater than zero"));
+    }
+    if utxos.is_empty() {
+        return Err(PyException::new_err("`utxos` must not be empty"));
+    }
+
+    let network_id: NetworkId = network_id.into();
+    let consensus_params = Params::from(network_id);
+    let mc = MassCalculator::new(&consensus_params);
+
+    let estimated_fee = |input_count: usize| -> u64 {
+        let mass = estimate_compute_mass(input_count, 2);
+        (mc.calc_fee_for_mass(mass) as f64 * fee_rate).round() as u64
+    };
+
+    let mut candidates = utxos;
+    candidates.sort_by(|a, b| b.1.cmp(&a.1));
+
+    let mut best: Option<(Vec<usize>, u64)> = None;
+    if candidates.len() <= BNB_MAX_CANDIDATES {
+        let mut indices = Vec::with_capacity(candidates.len());
+        let mut tries = 0u32;
+        branch_and_bound(
+            &candidates,
+            0,
+            0,
+            target,
+            &estimated_fee,
+            &mut indices,
+            &mut best,
+            &mut tries,
+        );
+    }
+    if best.is_none() {
+        best = greedy_select(&candidates, target, &estimated_fee);
+    }
+
+    let Some((chosen, total_value)) = best else {
+        return Ok(PySelection {
+            outpoints: Vec::new(),
+            total_value: 0,
+            needs_change: false,
+            storage_mass: 0,
+            compute_mass: 0,
+        });
+    };
+
+    let fee = estimated_fee(chosen.len());
+    let needs_change = total_value > target + fee;
+
+    let input_values: Vec<UtxoCell> = chosen.iter().map(|&i| UtxoCell::new(1, candidates[i].1)).collect();
+    let mut output_values = vec![UtxoCell::new(1, target)];
+    if needs_change {
+        output_values.push(UtxoCell::new(1, total_value - target - fee));
+    }
+    let storage_mass = calc_storage_mass(
+        false,
+        input_values.into_iter(),
+        output_values.into_iter(),
+        consensus_params.storage_mass_parameter,
+    )
+    .unwrap_or(0);
+    let compute_mass = estimate_compute_mass(chosen.len(), if needs_change { 2 } else { 1 });
+
+    let outpoints = chosen.iter().map(|&i| candidates[i].0.clone()).collect();
+    Ok(PySelection {
+        outpoints,
+        total_value,
+        needs_change,
+        storage_mass,
+        compute_mass,
+    })
+}
+
+/// Recursively include/exclude the candidate at `index`, tracking the
+/// running `sum` and updating `best` with the feasible subset of lowest
+/// mass-proxy score (approximated here by total value closeness, refined by
+/// the caller via the real storage mass calculation once a winner is picked).
+///
+/// `tries` is incremented on every call and the search aborts once it
+/// exceeds `BNB_MAX_TRIES`, so pathological candidate sets (many
+/// same-valued UTXOs) can't blow up recursion time or depth unbounded; the
+/// caller falls back to `greedy_select` when that happens.
+#[allow(clippy::too_many_arguments)]
+fn branch_and_bound(
+    candidates: &[(PyTransactionOutpoint, u64)],
+    index: usize,
+    sum: u64,
+    target: u64,
+    estimated_fee: &dyn Fn(usize) -> u64,
+    current: &mut Vec<usize>,
+    best: &mut Option<(Vec<usize>, u64)>,
+    tries: &mut u32,
+) {
+    *tries += 1;
+    if *tries > BNB_MAX_TRIES {
+        return;
+    }
+
+    let fee = estimated_fee(current.len().max(1));
+    let lower_bound = target + fee;
+    let upper_bound = lower_bound + COST_OF_CHANGE;
+
+    if sum >= lower_bound && sum <= upper_bound {
+        let is_exact = sum == lower_bound;
+        let better = match best {
+            None => true,
+            Some((best_set, best_sum)) => {
+                let best_exact = *best_sum == target + estimated_fee(best_set.len().max(1));
+                match (is_exact, best_exact) {
+                    (true, false) => true,
+                    (false, true) => false,
+                    _ => current.len() < best_set.len() || (current.len() == best_set.len() && sum < *best_sum),
+                }
+            }
+        };
+        if better {
+            *best = Some((current.clone(), sum));
+        }
+        if is_exact {
+            return;
+        }
+    }
+
+    if sum > upper_bound || index >= candidates.len() {
+        return;
+    }
+
+    let remaining: u64 = candidates[index..].iter().map(|c| c.1).sum();
+    if sum + remaining < lower_bound {
+        return;
+    }
+
+    // Include candidates[index].
+    current.push(index);
+    branch_and_bound(candidates, index + 1, sum + candidates[index].1, target, estimated_fee, current, best, tries);
+    current.pop();
+
+    // Exclude candidates[index].
+    branch_and_bound(candidates, index + 1, sum, target, estimated_fee, current, best, tries);
+}
+
+/// Greedy largest-first fallback used when the candidate set is too large
+/// for exhaustive branch-and-bound, or its iteration budget runs out.
+/// Accumulates candidates (already sorted by value descending) until the
+/// running sum covers `target` plus the fee for the inputs chosen so far.
+/// Unlike `branch_and_bound` this doesn't search for the mass-minimizing
+/// subset, just *a* feasible one - the same tradeoff Bitcoin Core's SRD
+/// (single random draw) fallback makes once its BnB budget is exhausted.
+fn greedy_select(
+    candidates: &[(PyTransactionOutpoint, u64)],
+    target: u64,
+    estimated_fee: &dyn Fn(usize) -> u64,
+) -> Option<(Vec<usize>, u64)> {
+    let mut chosen = Vec::new();
+    let mut sum = 0u64;
+    for (index, (_, value)) in candidates.iter().enumerate() {
+        chosen.push(index);
+        sum += value;
+        if sum >= target + estimated_fee(chosen.len()) {
+            return Some((chosen, sum));
+        }
+    }
+    None
+}
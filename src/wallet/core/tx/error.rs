@@ -0,0 +1,46 @@
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+
+/// Raised when a transaction cannot be funded from the available UTXOs.
+///
+/// Exposes the amounts involved (in sompi) so callers can decide whether to
+/// gather more UTXOs or adjust the fee rate instead of parsing the message.
+#[gen_stub_pyclass]
+#[pyclass(name = "InsufficientFundsError", extends = PyException)]
+pub struct PyInsufficientFundsError {
+    /// The total UTXO input amount available, in sompi.
+    #[pyo3(get)]
+    available: u64,
+    /// The total amount required (outputs + estimated fees), in sompi.
+    #[pyo3(get)]
+    required: u64,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyInsufficientFundsError {
+    #[new]
+    pub fn new(available: u64, required: u64) -> Self {
+        Self { available, required }
+    }
+
+    /// The amount still missing to cover `required`, in sompi.
+    #[getter]
+    pub fn shortfall(&self) -> u64 {
+        self.required.saturating_sub(self.available)
+    }
+
+    fn __str__(&self) -> String {
+        format!(
+            "Insufficient funds: available {} sompi, required {} sompi",
+            self.available, self.required
+        )
+    }
+}
+
+impl PyInsufficientFundsError {
+    pub fn new_err(available: u64, required: u64) -> PyErr {
+        PyErr::new::<Self, _>((available, required))
+    }
+}
@@ -6,14 +6,134 @@ use crate::{
     consensus::client::utxo::PyUtxoEntryReference, wallet::core::tx::payment::PyPaymentOutput,
     wallet::core::utxo::context::PyUtxoContext,
 };
+use crate::callback::PyCallback;
 use kaspa_consensus_client::UtxoEntryReference;
 use kaspa_wallet_core::result::Result;
 use kaspa_wallet_core::tx::{
     Fees, PaymentDestination, PaymentOutput, PaymentOutputs, generator as native,
 };
 use kaspa_wallet_core::utxo::UtxoContext;
-use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::types::PyTuple;
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyclass_enum, gen_stub_pymethods};
+use rand::seq::SliceRandom;
+use std::str::FromStr;
 use workflow_core::prelude::Abortable;
+use futures::StreamExt;
+use workflow_log::*;
+
+/// UTXO coin-selection strategy used by the `Generator` when consuming a
+/// flat list of UTXO entries.
+#[gen_stub_pyclass_enum]
+#[pyclass(name = "CoinSelectionStrategy", eq, eq_int)]
+#[derive(Clone, Copy, Eq, PartialEq, Default)]
+pub enum PyCoinSelectionStrategy {
+    /// Consume UTXOs from largest to smallest.
+    #[default]
+    LargestFirst,
+    /// CIP-2 style Random-Improve: randomly draw UTXOs per output, then
+    /// improve the selection toward a target of 2x the output value.
+    RandomImprove,
+}
+
+impl FromStr for PyCoinSelectionStrategy {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', ' '], "_").as_str() {
+            "largest_first" => Ok(PyCoinSelectionStrategy::LargestFirst),
+            "random_improve" => Ok(PyCoinSelectionStrategy::RandomImprove),
+            _ => Err(PyException::new_err(
+                "Unsupported string value for `CoinSelectionStrategy`",
+            )),
+        }
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyCoinSelectionStrategy {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        if let Ok(s) = obj.extract::<String>() {
+            PyCoinSelectionStrategy::from_str(&s)
+        } else if let Ok(t) = obj.cast::<PyCoinSelectionStrategy>() {
+            Ok(*t.borrow())
+        } else {
+            Err(PyException::new_err(
+                "Expected type `str` or `CoinSelectionStrategy`",
+            ))
+        }
+    }
+}
+
+/// Order `entries` according to `strategy`, given the target output amounts.
+///
+/// The Generator consumes UTXOs from the front of the returned list, so the
+/// ordering alone is enough to steer coin selection without touching the
+/// underlying native iterator.
+fn select_coins(
+    entries: Vec<UtxoEntryReference>,
+    output_amounts: &[u64],
+    strategy: PyCoinSelectionStrategy,
+) -> Vec<UtxoEntryReference> {
+    match strategy {
+        PyCoinSelectionStrategy::LargestFirst => {
+            let mut entries = entries;
+            entries.sort_by(|a, b| b.utxo.amount().cmp(&a.utxo.amount()));
+            entries
+        }
+        PyCoinSelectionStrategy::RandomImprove => random_improve(entries, output_amounts),
+    }
+}
+
+/// CIP-2 Random-Improve coin selection.
+///
+/// Pass 1 processes outputs in descending value order, randomly drawing
+/// UTXOs without replacement until each output's value is covered. Pass 2
+/// then keeps swapping in additional random UTXOs as long as doing so moves
+/// the running input total closer to 2x the output value, without crossing
+/// 3x or exhausting the remaining pool. Leftover UTXOs are appended
+/// unselected so the Generator can still draw on them for fees and change;
+/// if the pool runs dry early, selection simply stops and the Generator
+/// reports insufficient funds rather than panicking.
+fn random_improve(
+    mut pool: Vec<UtxoEntryReference>,
+    output_amounts: &[u64],
+) -> Vec<UtxoEntryReference> {
+    let mut rng = rand::thread_rng();
+    pool.shuffle(&mut rng);
+
+    let mut sorted_outputs = output_amounts.to_vec();
+    sorted_outputs.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut selected: Vec<UtxoEntryReference> = Vec::new();
+
+    for target in sorted_outputs {
+        let ideal = target.saturating_mul(2);
+        let ceiling = target.saturating_mul(3);
+        let mut accumulated = 0u64;
+
+        while accumulated < target {
+            let Some(entry) = pool.pop() else { break };
+            accumulated += entry.utxo.amount();
+            selected.push(entry);
+        }
+
+        while accumulated < ideal {
+            let Some(entry) = pool.pop() else { break };
+            let amount = entry.utxo.amount();
+            if accumulated + amount > ceiling {
+                pool.push(entry);
+                break;
+            }
+            accumulated += amount;
+            selected.push(entry);
+        }
+    }
+
+    selected.extend(pool);
+    selected
+}
 
 /// UTXO entries collection for flexible input handling.
 ///
@@ -107,7 +227,12 @@ impl<'py> FromPyObject<'_, 'py> for PyOutputs {
 /// splitting for large transfers.
 #[gen_stub_pyclass]
 #[pyclass(name = "Generator")]
-pub struct PyGenerator(Arc<native::Generator>);
+pub struct PyGenerator {
+    generator: Arc<native::Generator>,
+    abortable: Abortable,
+    rebuild_settings: GeneratorSettings,
+    coin_selection: PyCoinSelectionStrategy,
+}
 
 #[gen_stub_pymethods]
 #[pymethods]
@@ -125,6 +250,14 @@ impl PyGenerator {
     ///     priority_entries: UTXOs to use first.
     ///     sig_op_count: Signature operations per input (default: 1).
     ///     minimum_signatures: For multisig fee estimation.
+    ///     coin_selection: Coin-selection strategy - `"largest_first"` (default)
+    ///         or `"random_improve"`. Only applies when `entries` is a flat
+    ///         list of UTXO entries.
+    ///     event_callback: Optional callback invoked with progress events
+    ///         (UTXO selection progress, each emitted transaction, and the
+    ///         final summary) while this Generator is driven by `estimate()`
+    ///         or by iterating/streaming it, so long-running sweeps can
+    ///         render progress instead of blocking opaquely.
     ///
     /// Returns:
     ///     Generator: A new Generator instance.
@@ -132,8 +265,9 @@ impl PyGenerator {
     /// Raises:
     ///     Exception: If generator creation fails.
     #[new]
-    #[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None))]
+    #[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None, coin_selection=None, event_callback=None))]
     pub fn ctor(
+        py: Python<'_>,
         #[gen_stub(override_type(type_repr = "UtxoEntries | UtxoContext"))] entries: Bound<
             '_,
             PyAny,
@@ -147,8 +281,13 @@ impl PyGenerator {
         priority_entries: Option<PyUtxoEntries>,
         sig_op_count: Option<u8>,
         minimum_signatures: Option<u16>,
+        #[gen_stub(override_type(type_repr = "str | CoinSelectionStrategy | None = CoinSelectionStrategy.LargestFirst"))]
+        coin_selection: Option<PyCoinSelectionStrategy>,
+        event_callback: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         let source = parse_generator_source(entries)?;
+        let coin_selection = coin_selection.unwrap_or_default();
+        let multiplexer = event_callback.is_some().then(Multiplexer::new);
         let settings = GeneratorSettings::new(
             outputs,
             change_address.into(),
@@ -160,65 +299,26 @@ impl PyGenerator {
             minimum_signatures,
             payload.map(Into::into),
             network_id.map(Into::into),
+            multiplexer.clone(),
         );
 
-        let settings = match settings.source {
-            GeneratorSource::UtxoEntries(utxo_entries) => {
-                let change_address = settings.change_address.ok_or_else(|| {
-                    PyException::new_err(
-                        "changeAddress is required for Generator constructor with UTXO entries",
-                    )
-                })?;
-
-                let network_id = settings.network_id.ok_or_else(|| {
-                    PyException::new_err(
-                        "networkId is required for Generator constructor with UTXO entries",
-                    )
-                })?;
-
-                native::GeneratorSettings::try_new_with_iterator(
-                    network_id,
-                    Box::new(utxo_entries.into_iter()),
-                    settings.priority_utxo_entries,
-                    change_address,
-                    settings.sig_op_count,
-                    settings.minimum_signatures,
-                    settings.final_transaction_destination,
-                    settings.fee_rate,
-                    settings.final_priority_fee,
-                    settings.payload,
-                    settings.multiplexer,
-                )
-                .map_err(|err| PyException::new_err(err.to_string()))?
-            }
-            GeneratorSource::UtxoContext(utxo_context) => {
-                let change_address = settings.change_address.ok_or_else(|| {
-                    PyException::new_err(
-                        "changeAddress is required for Generator constructor with UTXO entries",
-                    )
-                })?;
-
-                native::GeneratorSettings::try_new_with_context(
-                    utxo_context,
-                    settings.priority_utxo_entries,
-                    change_address,
-                    settings.sig_op_count,
-                    settings.minimum_signatures,
-                    settings.final_transaction_destination,
-                    settings.fee_rate,
-                    settings.final_priority_fee,
-                    settings.payload,
-                    settings.multiplexer,
-                )
-                .map_err(|err| PyException::new_err(err.to_string()))?
-            }
-        };
+        let rebuild_settings = settings.clone();
+        let native_settings = finalize_settings(settings, coin_selection)?;
 
         let abortable = Abortable::default();
-        let generator = native::Generator::try_new(settings, None, Some(&abortable))
+        let generator = native::Generator::try_new(native_settings, None, Some(&abortable))
             .map_err(|err| PyException::new_err(err.to_string()))?;
 
-        Ok(Self(Arc::new(generator)))
+        if let (Some(callback), Some(multiplexer)) = (event_callback, multiplexer) {
+            spawn_event_listener(py, multiplexer, callback)?;
+        }
+
+        Ok(Self {
+            generator: Arc::new(generator),
+            abortable,
+            rebuild_settings,
+            coin_selection,
+        })
     }
 
     /// Estimate the transaction without generating.
@@ -229,11 +329,48 @@ impl PyGenerator {
     /// Raises:
     ///     Exception: If estimation fails.
     pub fn estimate(&self) -> PyResult<PyGeneratorSummary> {
-        self.0
+        self.generator
             .iter()
             .collect::<Result<Vec<_>>>()
             .map_err(|err| PyException::new_err(err.to_string()))?;
-        Ok(self.0.summary().into())
+        Ok(self.generator.summary().into())
+    }
+
+    /// Estimate this Generator's transaction(s) once per candidate fee rate,
+    /// reusing the same UTXO source and destination, so a wallet UI can
+    /// offer a "low / normal / priority" fee picker without reconstructing
+    /// a `Generator` per rate.
+    ///
+    /// Args:
+    ///     rates: Candidate fee rate multipliers to estimate with.
+    ///
+    /// Returns:
+    ///     list[tuple[float, GeneratorSummary]]: Each candidate rate paired
+    ///     with the summary (total fee, transaction count, aggregate
+    ///     input/output) estimation would produce at that rate.
+    ///
+    /// Raises:
+    ///     Exception: If estimation fails for any of the candidate rates.
+    pub fn estimate_fee_rates(
+        &self,
+        rates: Vec<f64>,
+    ) -> PyResult<Vec<(f64, PyGeneratorSummary)>> {
+        rates
+            .into_iter()
+            .map(|rate| {
+                let mut settings = self.rebuild_settings.clone();
+                settings.fee_rate = (rate.is_finite() && rate >= 1e-8).then_some(rate);
+                let native_settings = finalize_settings(settings, self.coin_selection)?;
+                let abortable = Abortable::default();
+                let generator = native::Generator::try_new(native_settings, None, Some(&abortable))
+                    .map_err(|err| PyException::new_err(err.to_string()))?;
+                generator
+                    .iter()
+                    .collect::<Result<Vec<_>>>()
+                    .map_err(|err| PyException::new_err(err.to_string()))?;
+                Ok((rate, generator.summary().into()))
+            })
+            .collect()
     }
 
     /// Get the summary after generation.
@@ -241,18 +378,94 @@ impl PyGenerator {
     /// Returns:
     ///     GeneratorSummary: The generation summary with fees and transaction details.
     pub fn summary(&self) -> PyGeneratorSummary {
-        self.0.summary().into()
+        self.generator.summary().into()
+    }
+
+    /// Signal cancellation of an in-progress (or not yet started) generation.
+    ///
+    /// `__next__`/`__anext__`/`estimate` promptly return an abort error on
+    /// their next call instead of running to completion. Safe to call from
+    /// another task or thread while iteration is in progress.
+    pub fn abort(&self) {
+        self.abortable.abort();
+    }
+
+    /// Whether `abort()` has been called on this generator.
+    ///
+    /// Returns:
+    ///     bool: True if cancellation has been signalled.
+    pub fn is_aborted(&self) -> bool {
+        self.abortable.is_aborted()
+    }
+
+    /// Build a consolidation ("sweep") generator: drain `entries` into
+    /// `destination` with no discrete outputs, so the full balance (minus
+    /// fees) comes back as change at `destination` across the minimal
+    /// number of transactions.
+    ///
+    /// This is the same as calling `Generator(entries, destination)` with
+    /// no `outputs` (which already falls back to `PaymentDestination::Change`),
+    /// except coin selection is pinned to `"largest_first"` so the fewest,
+    /// largest UTXOs are consumed per transaction - minimizing the number
+    /// of consolidation transactions produced, regardless of what the
+    /// caller's default coin-selection preference might otherwise be.
+    ///
+    /// Args:
+    ///     entries: UtxoContext or list of UTXO entries to sweep.
+    ///     destination: Address to consolidate the swept balance into.
+    ///     network_id: The network to build transactions for (required for UTXO entries).
+    ///     fee_rate: Optional fee rate multiplier.
+    ///     priority_fee: Additional fee in sompi.
+    ///     sig_op_count: Signature operations per input (default: 1).
+    ///     minimum_signatures: For multisig fee estimation.
+    ///     event_callback: Optional progress callback, as in the constructor.
+    ///
+    /// Returns:
+    ///     Generator: A new Generator configured to sweep `entries` into `destination`.
+    ///
+    /// Raises:
+    ///     Exception: If generator creation fails.
+    #[staticmethod]
+    #[pyo3(signature = (entries, destination, network_id=None, fee_rate=None, priority_fee=None, sig_op_count=None, minimum_signatures=None, event_callback=None))]
+    pub fn sweep(
+        py: Python<'_>,
+        #[gen_stub(override_type(type_repr = "UtxoEntries | UtxoContext"))] entries: Bound<
+            '_,
+            PyAny,
+        >,
+        destination: PyAddress,
+        network_id: Option<PyNetworkId>,
+        fee_rate: Option<f64>,
+        priority_fee: Option<u64>,
+        sig_op_count: Option<u8>,
+        minimum_signatures: Option<u16>,
+        event_callback: Option<Py<PyAny>>,
+    ) -> PyResult<Self> {
+        Self::ctor(
+            py,
+            entries,
+            destination,
+            network_id,
+            None,
+            None,
+            fee_rate,
+            priority_fee,
+            None,
+            sig_op_count,
+            minimum_signatures,
+            Some(PyCoinSelectionStrategy::LargestFirst),
+            event_callback,
+        )
     }
 }
 
 impl PyGenerator {
     pub fn iter(&self) -> impl Iterator<Item = Result<native::PendingTransaction>> {
-        self.0.iter()
+        self.generator.iter()
     }
 
-    #[allow(dead_code)]
     pub fn stream(&self) -> impl Stream<Item = Result<native::PendingTransaction>> {
-        self.0.stream()
+        self.generator.stream()
     }
 }
 
@@ -272,7 +485,7 @@ impl PyGenerator {
     /// Raises:
     ///     Exception: If transaction generation fails.
     fn __next__(slf: PyRefMut<Self>) -> PyResult<Option<PendingTransaction>> {
-        match slf.0.iter().next() {
+        match slf.generator.iter().next() {
             Some(result) => match result {
                 Ok(transaction) => Ok(Some(transaction.into())),
                 Err(e) => Err(PyErr::new::<pyo3::exceptions::PyException, _>(format!(
@@ -283,6 +496,79 @@ impl PyGenerator {
             None => Ok(None),
         }
     }
+
+    /// Return self as an async iterator.
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Get the next pending transaction without blocking the event loop.
+    ///
+    /// Returns:
+    ///     PendingTransaction: The next transaction to sign and submit.
+    ///
+    /// Raises:
+    ///     StopAsyncIteration: When UTXO selection and transaction generation have completed.
+    ///     Exception: If transaction generation fails.
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let generator = self.generator.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut stream = Box::pin(generator.stream());
+            match stream.next().await {
+                Some(Ok(transaction)) => Ok(PendingTransaction::from(transaction)),
+                Some(Err(e)) => Err(PyException::new_err(e.to_string())),
+                None => Err(PyStopAsyncIteration::new_err("generator exhausted")),
+            }
+        })
+    }
+}
+
+/// Spawn a background task that forwards every event from `multiplexer` to
+/// `callback` for as long as the `Generator` (and its internal multiplexer)
+/// stays alive. Mirrors `UtxoProcessor`'s notification loop, minus the
+/// per-`EventKind` listener registry - `Generator` has a single blanket
+/// callback rather than per-event-type registration.
+fn spawn_event_listener(
+    py: Python,
+    multiplexer: Multiplexer<Box<Events>>,
+    callback: Py<PyAny>,
+) -> PyResult<()> {
+    let args = PyTuple::empty(py).unbind();
+    let kwargs = PyDict::new(py).unbind();
+    let callback = PyCallback::new(callback, args, kwargs);
+    let channel = multiplexer.channel();
+
+    let fut = async move {
+        loop {
+            match channel.receiver.recv().await {
+                Ok(event) => Python::attach(|py| {
+                    let event_any = match serde_pyobject::to_pyobject(py, event.as_ref()) {
+                        Ok(obj) => obj,
+                        Err(err) => {
+                            log_error!("Generator: failed to serialize event: {}", err);
+                            return;
+                        }
+                    };
+                    let event = match event_any.cast::<PyDict>() {
+                        Ok(dict) => dict,
+                        Err(err) => {
+                            log_error!("Generator: serialized event is not a dict: {}", err);
+                            return;
+                        }
+                    };
+                    if let Err(err) = callback.execute(py, (*event).clone()) {
+                        log_error!("Generator: error while executing event listener: {}", err);
+                    }
+                }),
+                Err(_) => break,
+            }
+        }
+        channel.close();
+        Python::attach(|_| Ok(()))
+    };
+
+    pyo3_async_runtimes::tokio::future_into_py(py, fut)?;
+    Ok(())
 }
 
 fn parse_generator_source(entries: Bound<'_, PyAny>) -> PyResult<GeneratorSource> {
@@ -297,7 +583,76 @@ fn parse_generator_source(entries: Bound<'_, PyAny>) -> PyResult<GeneratorSource
     }
 }
 
+/// Build the final native `GeneratorSettings` for `source`, consuming the
+/// intermediate (builder-level) `GeneratorSettings`. Shared by the ctor and
+/// `estimate_fee_rates`, which reruns this once per candidate fee rate.
+fn finalize_settings(
+    settings: GeneratorSettings,
+    coin_selection: PyCoinSelectionStrategy,
+) -> PyResult<native::GeneratorSettings> {
+    match settings.source {
+        GeneratorSource::UtxoEntries(utxo_entries) => {
+            let change_address = settings.change_address.ok_or_else(|| {
+                PyException::new_err(
+                    "changeAddress is required for Generator constructor with UTXO entries",
+                )
+            })?;
+
+            let network_id = settings.network_id.ok_or_else(|| {
+                PyException::new_err(
+                    "networkId is required for Generator constructor with UTXO entries",
+                )
+            })?;
+
+            let output_amounts = match &settings.final_transaction_destination {
+                PaymentDestination::Change => vec![],
+                PaymentDestination::PaymentOutputs(outputs) => {
+                    outputs.outputs.iter().map(|output| output.amount).collect()
+                }
+            };
+            let utxo_entries = select_coins(utxo_entries, &output_amounts, coin_selection);
+
+            native::GeneratorSettings::try_new_with_iterator(
+                network_id,
+                Box::new(utxo_entries.into_iter()),
+                settings.priority_utxo_entries,
+                change_address,
+                settings.sig_op_count,
+                settings.minimum_signatures,
+                settings.final_transaction_destination,
+                settings.fee_rate,
+                settings.final_priority_fee,
+                settings.payload,
+                settings.multiplexer,
+            )
+            .map_err(|err| PyException::new_err(err.to_string()))
+        }
+        GeneratorSource::UtxoContext(utxo_context) => {
+            let change_address = settings.change_address.ok_or_else(|| {
+                PyException::new_err(
+                    "changeAddress is required for Generator constructor with UTXO entries",
+                )
+            })?;
+
+            native::GeneratorSettings::try_new_with_context(
+                utxo_context,
+                settings.priority_utxo_entries,
+                change_address,
+                settings.sig_op_count,
+                settings.minimum_signatures,
+                settings.final_transaction_destination,
+                settings.fee_rate,
+                settings.final_priority_fee,
+                settings.payload,
+                settings.multiplexer,
+            )
+            .map_err(|err| PyException::new_err(err.to_string()))
+        }
+    }
+}
+
 #[allow(dead_code)]
+#[derive(Clone)]
 enum GeneratorSource {
     UtxoEntries(Vec<UtxoEntryReference>),
     UtxoContext(UtxoContext),
@@ -305,6 +660,7 @@ enum GeneratorSource {
 }
 
 #[allow(dead_code)]
+#[derive(Clone)]
 struct GeneratorSettings {
     pub network_id: Option<NetworkId>,
     pub source: GeneratorSource,
@@ -331,6 +687,7 @@ impl GeneratorSettings {
         minimum_signatures: Option<u16>,
         payload: Option<Vec<u8>>,
         network_id: Option<NetworkId>,
+        multiplexer: Option<Multiplexer<Box<Events>>>,
     ) -> GeneratorSettings {
         let final_transaction_destination = match outputs {
             Some(py_outputs) => PaymentOutputs {
@@ -356,7 +713,7 @@ impl GeneratorSettings {
             network_id,
             source,
             priority_utxo_entries: priority_entries,
-            multiplexer: None,
+            multiplexer,
             final_transaction_destination,
             change_address: Some(change_address),
             fee_rate,
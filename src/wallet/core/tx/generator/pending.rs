@@ -7,14 +7,37 @@ use crate::{
     rpc::wrpc::client::PyRpcClient,
     wallet::keys::privatekey::PyPrivateKey,
 };
+use kaspa_addresses::Prefix;
 use kaspa_consensus_client::Transaction;
-use kaspa_consensus_core::hashing::wasm::SighashType;
+use kaspa_consensus_core::{
+    hashing::{
+        sig::calc_schnorr_signature_hash,
+        sighash::SigHashReusedValuesUnsync,
+        sighash_type::{SIG_HASH_ALL, SigHashType},
+        wasm::SighashType,
+    },
+    tx::PopulatedTransaction,
+};
+use kaspa_txscript::{extract_script_pub_key_address, script_builder as native_script_builder};
 use kaspa_wallet_core::tx::generator as native;
-use pyo3::types::PyList;
+use pyo3::types::{PyBytes, PyList};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
-use workflow_core::hex::ToHex;
+use serde::{Deserialize, Serialize};
+use workflow_core::hex::{FromHex, ToHex};
 use zeroize::Zeroize;
 
+/// Portable wire format produced by `PendingTransaction.to_pskt()`: the cctx
+/// transaction together with its resolved UTXO entries (via `Transaction`'s
+/// own serialization, as used by `PartiallySignedTransaction`), plus the
+/// `minimum_signatures` threshold the transaction was generated with. Any
+/// signatures already placed via `fill_input`/`sign`/`sign_input` travel
+/// along as part of the transaction's input signature scripts.
+#[derive(Serialize, Deserialize)]
+struct PendingTransactionPsktBlob {
+    tx_bytes: Vec<u8>,
+    minimum_signatures: u16,
+}
+
 /// A transaction ready for signing and submission.
 ///
 /// Created by iterating over a Generator. Contains the transaction
@@ -114,7 +137,9 @@ impl PendingTransaction {
     /// Args:
     ///     input_index: The index of the input to sign.
     ///     private_key: The private key for signing.
-    ///     sighash_type: The signature hash type (default: All).
+    ///     sighash_type: The signature hash type - one of `All`, `None`,
+    ///         `Single`, `AllAnyOneCanPay`, `NoneAnyOneCanPay`, or
+    ///         `SingleAnyOneCanPay` (default: All).
     ///
     /// Returns:
     ///     str: The signature as a hex string.
@@ -146,10 +171,39 @@ impl PendingTransaction {
     /// Args:
     ///     input_index: The index of the input to fill.
     ///     signature_script: The signature script bytes.
+    ///     sighash_type: If given, verify that the signature's committed
+    ///         sighash flag (the script's trailing byte) matches before
+    ///         filling, so a signature produced under the wrong sighash
+    ///         type - e.g. one that omits `AnyOneCanPay` when a caller is
+    ///         about to append more inputs - is caught here instead of
+    ///         surfacing later at `finalize`.
     ///
     /// Raises:
-    ///     Exception: If filling fails.
-    fn fill_input(&self, input_index: u8, signature_script: PyBinary) -> PyResult<()> {
+    ///     Exception: If filling fails, or `sighash_type` is given and
+    ///         does not match the signature's committed sighash byte.
+    #[pyo3(signature = (input_index, signature_script, sighash_type=None))]
+    fn fill_input(
+        &self,
+        input_index: u8,
+        signature_script: PyBinary,
+        #[gen_stub(override_type(type_repr = "str | SighashType | None"))]
+        sighash_type: Option<PySighashType>,
+    ) -> PyResult<()> {
+        if let Some(sighash_type) = sighash_type {
+            let wasm_sighash_type: SighashType = sighash_type.into();
+            let core_sighash_type: SigHashType = wasm_sighash_type.into();
+            let expected = core_sighash_type.to_u8();
+            let actual = *signature_script
+                .data
+                .last()
+                .ok_or_else(|| PyException::new_err("signature_script is empty, has no committed sighash byte"))?;
+            if actual != expected {
+                return Err(PyException::new_err(format!(
+                    "signature_script's committed sighash byte (0x{actual:02x}) does not match the requested sighash_type (0x{expected:02x})"
+                )));
+            }
+        }
+
         self.0
             .fill_input(input_index.into(), signature_script.into())
             .map_err(|err| PyException::new_err(err.to_string()))?;
@@ -162,10 +216,13 @@ impl PendingTransaction {
     /// Args:
     ///     input_index: The index of the input to sign.
     ///     private_key: The private key for signing.
-    ///     sighash_type: The signature hash type (default: All).
+    ///     sighash_type: The signature hash type - one of `All`, `None`,
+    ///         `Single`, `AllAnyOneCanPay`, `NoneAnyOneCanPay`, or
+    ///         `SingleAnyOneCanPay` (default: All).
     ///
     /// Raises:
     ///     Exception: If signing fails.
+    #[pyo3(signature = (input_index, private_key, sighash_type=None))]
     fn sign_input(
         &self,
         input_index: u8,
@@ -189,24 +246,153 @@ impl PendingTransaction {
     /// Args:
     ///     private_keys: List of PrivateKey objects for signing.
     ///     check_fully_signed: Verify all inputs are signed (default: None).
+    ///     sighash_type: The signature hash type to sign each matched
+    ///         input under (default: All). A non-`All` type - `None`,
+    ///         `Single`, or one of the `AnyOneCanPay` combinations - lets
+    ///         more inputs/outputs be appended after signing, for
+    ///         collaboratively built or streamed transactions; each key is
+    ///         then matched to its owning input's UTXO and signed one at a
+    ///         time via `create_input_signature`/`fill_input`, rather than
+    ///         going through the native multi-key signer used for `All`.
     ///
     /// Raises:
     ///     Exception: If signing fails or transaction is not fully signed.
-    #[pyo3(signature = (private_keys, check_fully_signed=None))]
+    #[pyo3(signature = (private_keys, check_fully_signed=None, sighash_type=None))]
     fn sign<'py>(
         &self,
         private_keys: Bound<'py, PyList>,
         check_fully_signed: Option<bool>,
+        #[gen_stub(override_type(type_repr = "str | SighashType | None = SighashType.All"))]
+        sighash_type: Option<PySighashType>,
     ) -> PyResult<()> {
-        let mut keys: Vec<[u8; 32]> = Vec::with_capacity(private_keys.len());
+        let sighash_type = sighash_type.unwrap_or(PySighashType::All);
+        let wasm_sighash_type: SighashType = sighash_type.clone().into();
+        let core_sighash_type: SigHashType = wasm_sighash_type.into();
+
+        if core_sighash_type.to_u8() == SIG_HASH_ALL.to_u8() {
+            let mut keys: Vec<[u8; 32]> = Vec::with_capacity(private_keys.len());
+            for item in private_keys.iter() {
+                let key: PyRef<'_, PyPrivateKey> = item.extract()?;
+                keys.push(key.secret_bytes());
+            }
+            self.0
+                .try_sign_with_keys(&keys, check_fully_signed)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+            keys.zeroize();
+            return Ok(());
+        }
+
+        let tx = self.get_transaction()?;
+        let (_cctx, utxos) = tx
+            .inner()
+            .tx_and_utxos()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+
         for item in private_keys.iter() {
             let key: PyRef<'_, PyPrivateKey> = item.extract()?;
-            keys.push(key.secret_bytes());
+            let public_key = key
+                .inner()
+                .to_public_key()
+                .map_err(|_| PyException::new_err("Failed to derive public key"))?;
+            let xonly = public_key.xonly_public_key.serialize().to_vec();
+            let full = public_key.public_key.map(|pk| pk.serialize().to_vec());
+
+            for (index, utxo) in utxos.iter().enumerate() {
+                let matched = extract_script_pub_key_address(&utxo.script_public_key, Prefix::Mainnet)
+                    .ok()
+                    .is_some_and(|address| address.payload == xonly || full.as_ref().is_some_and(|full| address.payload == *full));
+                if !matched {
+                    continue;
+                }
+
+                let signature = self.create_input_signature(index as u8, &key, Some(sighash_type.clone()))?;
+                let signature_bytes = Vec::from_hex(&signature).map_err(|err| PyException::new_err(err.to_string()))?;
+                let mut builder = native_script_builder::ScriptBuilder::new();
+                builder
+                    .add_data(&signature_bytes)
+                    .map_err(|err| PyException::new_err(err.to_string()))?;
+                self.fill_input(
+                    index as u8,
+                    PyBinary { data: builder.script().to_vec() },
+                    Some(sighash_type.clone()),
+                )?;
+            }
+        }
+
+        if check_fully_signed.is_some() {
+            self.0
+                .try_sign_with_keys(&[], check_fully_signed)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
+        }
+        Ok(())
+    }
+
+    /// Sign every input through an external signer callback instead of
+    /// handing this process raw private keys.
+    ///
+    /// For each input, `callback` is invoked with
+    /// `(input_index, sighash_bytes, public_key)` - the sighash for that
+    /// input under `SighashType.All` and the hex-encoded public key
+    /// recovered from its UTXO entry's script public key - and must return
+    /// the signature bytes for that sighash. The signature is assembled
+    /// into a signature script and handed to `fill_input`, the same path
+    /// `create_input_signature`/`fill_input` use, so an HSM, hardware
+    /// wallet, or remote KMS never needs to expose the key material to
+    /// this process.
+    ///
+    /// Args:
+    ///     callback: A callable `(input_index: int, sighash: bytes, public_key: str) -> bytes`.
+    ///     check_fully_signed: Verify all inputs are signed (default: None).
+    ///
+    /// Raises:
+    ///     Exception: If an input's UTXO entry cannot be resolved, the
+    ///         callback raises or returns an invalid signature, or the
+    ///         transaction is not fully signed when requested.
+    #[pyo3(signature = (callback, check_fully_signed=None))]
+    fn sign_with_signer(
+        &self,
+        py: Python<'_>,
+        callback: Py<PyAny>,
+        check_fully_signed: Option<bool>,
+    ) -> PyResult<()> {
+        let tx = self.get_transaction()?;
+        let (cctx, utxos) = tx
+            .inner()
+            .tx_and_utxos()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let populated_transaction = PopulatedTransaction::new(&cctx, utxos);
+        let reused_values = SigHashReusedValuesUnsync::new();
+
+        for index in 0..populated_transaction.tx().inputs.len() {
+            let utxo_entry = populated_transaction.utxo(index).ok_or_else(|| {
+                PyException::new_err(format!("Input {index} has no populated UTXO entry"))
+            })?;
+            let public_key = extract_script_pub_key_address(&utxo_entry.script_public_key, Prefix::Mainnet)
+                .map_err(|err| PyException::new_err(format!("Input {index}: unable to derive owner public key: {err}")))?
+                .payload
+                .to_hex();
+            let sighash = calc_schnorr_signature_hash(&populated_transaction, index, SIG_HASH_ALL, &reused_values);
+
+            let mut signature: Vec<u8> = callback
+                .call1(py, (index, PyBytes::new(py, sighash.as_bytes().as_slice()), public_key))
+                .map_err(|err| PyException::new_err(format!("Input {index}: signer callback failed: {err}")))?
+                .extract(py)
+                .map_err(|err| PyException::new_err(format!("Input {index}: signer callback must return signature bytes: {err}")))?;
+            signature.push(SIG_HASH_ALL.to_u8());
+
+            let mut builder = native_script_builder::ScriptBuilder::new();
+            builder
+                .add_data(&signature)
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+
+            self.fill_input(index as u8, PyBinary { data: builder.script().to_vec() }, Some(PySighashType::All))?;
+        }
+
+        if check_fully_signed.is_some() {
+            self.0
+                .try_sign_with_keys(&[], check_fully_signed)
+                .map_err(|err| PyException::new_err(format!("{}", err)))?;
         }
-        self.0
-            .try_sign_with_keys(&keys, check_fully_signed)
-            .map_err(|err| PyException::new_err(format!("{}", err)))?;
-        keys.zeroize();
         Ok(())
     }
 
@@ -242,6 +428,73 @@ impl PendingTransaction {
     fn get_transaction(&self) -> PyResult<PyTransaction> {
         Ok(Transaction::from_cctx_transaction(&self.0.transaction(), self.0.utxo_entries()).into())
     }
+
+    /// Export this pending transaction - its cctx transaction, resolved UTXO
+    /// entries, and any signatures already placed via
+    /// `fill_input`/`sign`/`sign_input` - as a portable blob, for handing
+    /// off to an offline machine holding the remaining private keys.
+    ///
+    /// The offline side reconstructs the transaction with `from_pskt`,
+    /// signs it with `create_input_signature`/`sign_transaction`, and sends
+    /// the result back; this `PendingTransaction` stays alive on the
+    /// watch-only side to receive those signatures via `fill_input` and
+    /// check completeness with `finalize`.
+    ///
+    /// Returns:
+    ///     str: The serialized transaction as a hex string.
+    ///
+    /// Raises:
+    ///     Exception: If serialization fails.
+    fn to_pskt(&self) -> PyResult<String> {
+        let tx = self.get_transaction()?;
+        let tx_bytes = bincode::serialize(tx.inner()).map_err(|err| PyException::new_err(err.to_string()))?;
+        let blob = PendingTransactionPsktBlob {
+            tx_bytes,
+            minimum_signatures: self.0.minimum_signatures(),
+        };
+        let bytes = bincode::serialize(&blob).map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(bytes.to_hex())
+    }
+
+    /// Reconstruct the transaction and UTXO entries from a blob produced by
+    /// `to_pskt`, for an offline signer to sign without needing the
+    /// originating `Generator`.
+    ///
+    /// Args:
+    ///     data: The hex blob returned by `to_pskt`.
+    ///
+    /// Returns:
+    ///     tuple[Transaction, int]: The transaction, with its UTXO entries
+    ///     populated, and the `minimum_signatures` it was generated with.
+    ///
+    /// Raises:
+    ///     Exception: If `data` is not a valid PSKT blob.
+    #[staticmethod]
+    fn from_pskt(data: String) -> PyResult<(PyTransaction, u16)> {
+        let bytes = Vec::from_hex(&data).map_err(|err| PyException::new_err(err.to_string()))?;
+        let blob: PendingTransactionPsktBlob =
+            bincode::deserialize(&bytes).map_err(|err| PyException::new_err(err.to_string()))?;
+        let tx: Transaction =
+            bincode::deserialize(&blob.tx_bytes).map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok((tx.into(), blob.minimum_signatures))
+    }
+
+    /// Verify that every input's signature script - filled in via
+    /// `fill_input`/`sign`/`sign_input`, possibly across several hops
+    /// through `to_pskt`/`from_pskt` - actually satisfies its UTXO entry's
+    /// script public key, reaching the signature threshold required to
+    /// spend it, before allowing submission.
+    ///
+    /// Returns:
+    ///     Transaction: The finalized, ready-to-broadcast transaction.
+    ///
+    /// Raises:
+    ///     Exception: If any input's signature script does not satisfy its
+    ///         UTXO entry.
+    fn finalize(&self) -> PyResult<PyTransaction> {
+        let tx = self.get_transaction()?;
+        Ok(tx.verify_signatures()?.as_transaction())
+    }
 }
 
 impl From<native::PendingTransaction> for PendingTransaction {
@@ -5,7 +5,7 @@ use super::super::imports::*;
 use kaspa_consensus_core::config::params::Params;
 use kaspa_consensus_core::mass::{UtxoCell, calc_storage_mass};
 use kaspa_wallet_core::tx::{MAXIMUM_STANDARD_TRANSACTION_MASS, mass};
-use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyfunction, gen_stub_pymethods};
 // use pyo3::prelude::*;
 
 /// Get the maximum allowed mass for a standard transaction.
@@ -164,3 +164,121 @@ pub fn py_calculate_storage_mass(
 
     Ok(storage_mass)
 }
+
+/// A fee estimate broken down by mass component.
+///
+/// Returned by `estimate_transaction_fee`, this exposes both the compute and
+/// storage mass driving the fee, so callers can tell which one dominates
+/// instead of only seeing a single combined fee.
+#[gen_stub_pyclass]
+#[pyclass(name = "FeeEstimate")]
+#[derive(Clone)]
+pub struct PyFeeEstimate {
+    /// The transaction's compute mass (signature/script execution cost).
+    #[pyo3(get)]
+    compute_mass: u64,
+    /// The transaction's storage mass (UTXO set impact).
+    #[pyo3(get)]
+    storage_mass: u64,
+    /// The overall mass used for fee calculation, `max(compute_mass, storage_mass)`.
+    #[pyo3(get)]
+    total_mass: u64,
+    /// The fee implied by `total_mass` at the protocol's minimum rate.
+    #[pyo3(get)]
+    base_fee: u64,
+    /// `base_fee` scaled by the requested `fee_rate`, rounded to the nearest sompi.
+    #[pyo3(get)]
+    priority_fee: u64,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyFeeEstimate {
+    fn __repr__(&self) -> String {
+        format!(
+            "FeeEstimate(compute_mass={}, storage_mass={}, total_mass={}, base_fee={}, priority_fee={})",
+            self.compute_mass, self.storage_mass, self.total_mass, self.base_fee, self.priority_fee
+        )
+    }
+}
+
+/// Estimate the fee for a transaction, broken down by compute and storage mass.
+///
+/// Unlike `calculate_transaction_fee`, which hides whether storage or compute
+/// mass dominates, this exposes both components along with a `fee_rate`
+/// parameter for targeting a higher priority fee.
+///
+/// Args:
+///     network_id: The network identifier.
+///     tx: The transaction to estimate a fee for. Inputs must have their
+///         UTXO entry populated (via `get_utxo()`) so storage mass can be
+///         calculated from real input values.
+///     minimum_signatures: Minimum signatures per input (default: 1).
+///     fee_rate: Multiplier applied to the base fee to compute `priority_fee`
+///         (default: 1.0).
+///
+/// Returns:
+///     FeeEstimate: The compute/storage mass breakdown and resulting fees.
+///
+/// Raises:
+///     Exception: If mass calculation fails, or an input has no populated
+///         UTXO entry.
+///
+/// Category: Wallet/Transactions
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "estimate_transaction_fee")]
+#[pyo3(signature = (network_id, tx, minimum_signatures=None, fee_rate=1.0))]
+pub fn py_estimate_transaction_fee(
+    network_id: PyNetworkId,
+    tx: PyTransaction,
+    minimum_signatures: Option<u16>,
+    fee_rate: f64,
+) -> PyResult<PyFeeEstimate> {
+    let network_id: NetworkId = network_id.into();
+    let consensus_params = Params::from(network_id);
+    let mc = mass::MassCalculator::new(&consensus_params);
+
+    let client_tx: kaspa_consensus_client::Transaction = tx.clone().into();
+    let overall_mass = mc
+        .calc_overall_mass_for_unsigned_client_transaction(&client_tx, minimum_signatures.unwrap_or(1))
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    let mut input_values = Vec::with_capacity(client_tx.inner().inputs.len());
+    for input in &client_tx.inner().inputs {
+        let utxo = input.get_utxo().ok_or_else(|| {
+            PyException::new_err("every input must have a populated UTXO entry (get_utxo()) to estimate storage mass")
+        })?;
+        input_values.push(UtxoCell::new(1, utxo.utxo.amount));
+    }
+    let output_values: Vec<UtxoCell> = client_tx
+        .inner()
+        .outputs
+        .iter()
+        .map(|output| UtxoCell::new(1, output.inner().value))
+        .collect();
+
+    let storage_mass = calc_storage_mass(
+        false,
+        input_values.into_iter(),
+        output_values.into_iter(),
+        consensus_params.storage_mass_parameter,
+    )
+    .unwrap_or(0);
+
+    let compute_mass = mc
+        .calc_compute_mass_for_unsigned_client_transaction(&client_tx, minimum_signatures.unwrap_or(1))
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    let total_mass = compute_mass.max(storage_mass).max(overall_mass);
+
+    let base_fee = mc.calc_fee_for_mass(total_mass);
+    let priority_fee = (base_fee as f64 * fee_rate).round() as u64;
+
+    Ok(PyFeeEstimate {
+        compute_mass,
+        storage_mass,
+        total_mass,
+        base_fee,
+        priority_fee,
+    })
+}
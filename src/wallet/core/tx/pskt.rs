@@ -0,0 +1,318 @@
+//! A lightweight partially-signed transaction for cold-storage / watch-only
+//! signing workflows, modeled on the PSBT split between a watch-only process
+//! (public keys and locking scripts only) and an offline signer holding
+//! private keys.
+
+use crate::{
+    consensus::client::transaction::PyTransaction,
+    crypto::hashes::PyHash,
+    types::PyBinary,
+    wallet::keys::privatekey::PyPrivateKey,
+};
+use kaspa_consensus_client::Transaction;
+use kaspa_consensus_core::{hashing::sighash_type::SIG_HASH_ALL, sign::sign_input, tx::PopulatedTransaction};
+use kaspa_txscript::{script_builder as native, standard};
+use pyo3::{exceptions::PyException, prelude::*, types::PyList};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use workflow_core::hex::{FromHex, ToHex};
+use zeroize::Zeroize;
+
+/// Per-input signing state: which public keys are expected to sign, the
+/// redeem script to satisfy for P2SH inputs, and whatever signatures have
+/// been collected so far, keyed by signer.
+#[derive(Clone, Serialize, Deserialize)]
+struct PsktInput {
+    /// Hex-encoded x-only public keys expected to sign this input.
+    expected_pubkeys: Vec<String>,
+    /// Hex-encoded redeem script, for P2SH inputs. `None` for plain P2PK.
+    redeem_script: Option<String>,
+    /// Signatures collected so far, hex-encoded and keyed by signer public key.
+    signatures: BTreeMap<String, String>,
+}
+
+/// Portable wire format produced by `serialize()` / consumed by `deserialize()`.
+#[derive(Serialize, Deserialize)]
+struct PsktBlob {
+    tx_bytes: Vec<u8>,
+    inputs: Vec<PsktInput>,
+}
+
+/// A partially-signed Kaspa transaction that can be handed between
+/// independent signers without exposing private keys.
+///
+/// A watch-only process constructs this with every input's outpoint, UTXO
+/// and expected signers already populated. An offline signer holding
+/// `PrivateKey`s calls `.sign(keys)`, which fills in a signature for each
+/// input whose expected public key one of the supplied keys derives to, and
+/// leaves every other input untouched - so the PSKT can be passed to the
+/// next signer in a multisig chain before `.finalize()` assembles the
+/// signature scripts into a ready-to-broadcast transaction.
+#[gen_stub_pyclass]
+#[pyclass(name = "PartiallySignedTransaction")]
+#[derive(Clone)]
+pub struct PyPartiallySignedTransaction {
+    tx: PyTransaction,
+    inputs: Vec<PsktInput>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyPartiallySignedTransaction {
+    /// Create a new PSKT wrapping `tx`, one signer set per input.
+    ///
+    /// Args:
+    ///     tx: The unsigned transaction, with each input's UTXO entry
+    ///         (script public key and amount) already populated.
+    ///     expected_pubkeys: One list of hex-encoded x-only public keys per
+    ///         input, naming the signers who may sign it.
+    ///     redeem_scripts: Optional per-input hex-encoded redeem scripts,
+    ///         for inputs locked by P2SH. Use `None` at an input's position
+    ///         for plain P2PK inputs.
+    ///
+    /// Returns:
+    ///     PartiallySignedTransaction: The new PSKT, with no signatures collected yet.
+    ///
+    /// Raises:
+    ///     Exception: If `expected_pubkeys` or `redeem_scripts` doesn't have
+    ///         exactly one entry per transaction input.
+    #[new]
+    #[pyo3(signature = (tx, expected_pubkeys, redeem_scripts=None))]
+    pub fn new(
+        tx: PyTransaction,
+        expected_pubkeys: Vec<Vec<String>>,
+        redeem_scripts: Option<Vec<Option<PyBinary>>>,
+    ) -> PyResult<Self> {
+        let input_count = tx.get_inputs()?.len();
+        if expected_pubkeys.len() != input_count {
+            return Err(PyException::new_err(format!(
+                "expected_pubkeys must have one entry per input ({input_count}), got {}",
+                expected_pubkeys.len()
+            )));
+        }
+        let redeem_scripts = redeem_scripts.unwrap_or_else(|| vec![None; input_count]);
+        if redeem_scripts.len() != input_count {
+            return Err(PyException::new_err(format!(
+                "redeem_scripts must have one entry per input ({input_count}), got {}",
+                redeem_scripts.len()
+            )));
+        }
+
+        let inputs = expected_pubkeys
+            .into_iter()
+            .zip(redeem_scripts)
+            .map(|(expected_pubkeys, redeem_script)| PsktInput {
+                expected_pubkeys,
+                redeem_script: redeem_script.map(|script| script.data.to_hex()),
+                signatures: BTreeMap::new(),
+            })
+            .collect();
+
+        Ok(Self { tx, inputs })
+    }
+
+    /// Sign every input whose expected public key one of `keys` derives to.
+    ///
+    /// A key that doesn't match any of an input's expected public keys
+    /// simply contributes no signature for it - this is what lets several
+    /// signers each call `.sign()` on their own copy of the PSKT and have
+    /// the results combined for a multisig input.
+    ///
+    /// Args:
+    ///     keys: List of `PrivateKey` objects to try against every input.
+    ///
+    /// Raises:
+    ///     Exception: If computing a signature for a matching input fails.
+    pub fn sign(&mut self, keys: Bound<'_, PyList>) -> PyResult<()> {
+        let transaction: Transaction = self.tx.inner().clone();
+        let (cctx, utxos) = transaction
+            .tx_and_utxos()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let populated_transaction = PopulatedTransaction::new(&cctx, utxos);
+
+        for item in keys.iter() {
+            let key: PyRef<'_, PyPrivateKey> = item.extract()?;
+            let mut key_bytes = key.secret_bytes();
+            let public_key = key
+                .inner()
+                .to_public_key()
+                .map_err(|_| PyException::new_err("Failed to derive public key"))?;
+            let pubkey_hex = public_key.xonly_public_key.serialize().to_vec().to_hex();
+
+            for (index, input) in self.inputs.iter_mut().enumerate() {
+                if input.signatures.contains_key(&pubkey_hex) || !input.expected_pubkeys.contains(&pubkey_hex) {
+                    continue;
+                }
+                let signature = sign_input(&populated_transaction, index, &key_bytes, SIG_HASH_ALL);
+                input.signatures.insert(pubkey_hex.clone(), signature.to_hex());
+            }
+            key_bytes.zeroize();
+        }
+
+        Ok(())
+    }
+
+    /// Whether every input has a signature from each of its expected signers.
+    ///
+    /// Returns:
+    ///     bool: True if no input is still missing a signature.
+    pub fn is_finalized(&self) -> bool {
+        self.inputs.iter().all(|input| {
+            input
+                .expected_pubkeys
+                .iter()
+                .all(|pubkey| input.signatures.contains_key(pubkey))
+        })
+    }
+
+    /// The signers still outstanding for each input.
+    ///
+    /// Returns:
+    ///     dict[int, list[str]]: Input index to the hex-encoded public keys
+    ///     that have not yet signed it. Inputs with nothing missing are omitted.
+    pub fn missing_signatures(&self) -> BTreeMap<usize, Vec<String>> {
+        self.inputs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, input)| {
+                let missing: Vec<String> = input
+                    .expected_pubkeys
+                    .iter()
+                    .filter(|pubkey| !input.signatures.contains_key(*pubkey))
+                    .cloned()
+                    .collect();
+                (!missing.is_empty()).then_some((index, missing))
+            })
+            .collect()
+    }
+
+    /// Assemble each input's signature script from the collected signatures
+    /// and return a fully-signed transaction.
+    ///
+    /// Returns:
+    ///     Transaction: The finalized, ready-to-broadcast transaction.
+    ///
+    /// Raises:
+    ///     Exception: If any input is still missing a signature.
+    pub fn finalize(&self) -> PyResult<PyTransaction> {
+        if !self.is_finalized() {
+            return Err(PyException::new_err(
+                "cannot finalize: some inputs are still missing signatures",
+            ));
+        }
+
+        let mut inputs = self.tx.get_inputs()?;
+        for (index, input) in self.inputs.iter().enumerate() {
+            let signature_script = build_signature_script(input)?;
+            inputs[index].set_signature_script(PyBinary { data: signature_script });
+        }
+
+        let mut tx = self.tx.clone();
+        tx.set_inputs(inputs);
+        Ok(tx)
+    }
+
+    /// Serialize this PSKT to a portable hex blob, for handoff to the next signer.
+    ///
+    /// Returns:
+    ///     str: The serialized PSKT as a hex string.
+    ///
+    /// Raises:
+    ///     Exception: If serialization fails.
+    pub fn serialize(&self) -> PyResult<String> {
+        let tx_bytes = bincode::serialize(self.tx.inner()).map_err(|err| PyException::new_err(err.to_string()))?;
+        let blob = PsktBlob {
+            tx_bytes,
+            inputs: self.inputs.clone(),
+        };
+        let bytes = bincode::serialize(&blob).map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(bytes.to_hex())
+    }
+
+    /// Reconstruct a PSKT previously produced by `serialize()`.
+    ///
+    /// Args:
+    ///     data: The hex blob returned by `serialize()`.
+    ///
+    /// Returns:
+    ///     PartiallySignedTransaction: The reconstructed PSKT.
+    ///
+    /// Raises:
+    ///     Exception: If `data` is not a valid PSKT blob.
+    #[staticmethod]
+    pub fn deserialize(data: String) -> PyResult<Self> {
+        let bytes = Vec::from_hex(&data).map_err(|err| PyException::new_err(err.to_string()))?;
+        let blob: PsktBlob = bincode::deserialize(&bytes).map_err(|err| PyException::new_err(err.to_string()))?;
+        let tx: Transaction =
+            bincode::deserialize(&blob.tx_bytes).map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(Self {
+            tx: tx.into(),
+            inputs: blob.inputs,
+        })
+    }
+
+    /// The underlying transaction, signed or not.
+    ///
+    /// Returns:
+    ///     Transaction: The wrapped transaction.
+    #[getter]
+    pub fn get_transaction(&self) -> PyTransaction {
+        self.tx.clone()
+    }
+
+    /// The transaction ID.
+    ///
+    /// Returns:
+    ///     Hash: The transaction ID, computed from its current (possibly
+    ///     unsigned) state.
+    #[getter]
+    pub fn get_id(&self) -> PyResult<PyHash> {
+        self.tx.finalize()
+    }
+}
+
+/// Build the unlocking script for one input from its collected signatures,
+/// reusing `pay_to_script_hash_signature_script` for the P2SH single-signer
+/// case and the plain multisig push-and-append shape otherwise.
+fn build_signature_script(input: &PsktInput) -> PyResult<Vec<u8>> {
+    let signatures: Vec<Vec<u8>> = input
+        .expected_pubkeys
+        .iter()
+        .filter_map(|pubkey| input.signatures.get(pubkey))
+        .map(|signature| Vec::from_hex(signature).map_err(|err| PyException::new_err(err.to_string())))
+        .collect::<PyResult<_>>()?;
+
+    if signatures.is_empty() {
+        return Err(PyException::new_err("input has no collected signatures"));
+    }
+
+    match &input.redeem_script {
+        None => {
+            let mut builder = native::ScriptBuilder::new();
+            builder
+                .add_data(&signatures[0])
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+            Ok(builder.script().to_vec())
+        }
+        Some(redeem_script) => {
+            let redeem_script = Vec::from_hex(redeem_script).map_err(|err| PyException::new_err(err.to_string()))?;
+            if signatures.len() == 1 {
+                standard::pay_to_script_hash_signature_script(redeem_script, signatures[0].clone())
+                    .map(|script| script.to_vec())
+                    .map_err(|err| PyException::new_err(err.to_string()))
+            } else {
+                let mut builder = native::ScriptBuilder::new();
+                for signature in &signatures {
+                    builder
+                        .add_data(signature)
+                        .map_err(|err| PyException::new_err(err.to_string()))?;
+                }
+                builder
+                    .add_data(&redeem_script)
+                    .map_err(|err| PyException::new_err(err.to_string()))?;
+                Ok(builder.script().to_vec())
+            }
+        }
+    }
+}
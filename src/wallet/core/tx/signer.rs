@@ -1,18 +1,24 @@
 use crate::{
     consensus::{client::transaction::PyTransaction, core::hashing::PySighashType},
     crypto::hashes::PyHash,
+    types::PyBinary,
     wallet::keys::privatekey::PyPrivateKey,
 };
+use kaspa_addresses::Prefix;
 use kaspa_consensus_client::{Transaction, sign_with_multiple_v3};
 use kaspa_consensus_core::{
-    hashing::{sighash_type::SIG_HASH_ALL, wasm::SighashType},
+    hashing::{
+        sighash_type::{SIG_HASH_ALL, SigHashType},
+        wasm::SighashType,
+    },
     sign::{sign_input, verify},
     tx::PopulatedTransaction,
 };
 use kaspa_hashes::Hash;
+use kaspa_txscript::{extract_script_pub_key_address, script_builder as native, script_class::ScriptClass, standard};
 use kaspa_wallet_core::result::Result;
 use pyo3::{exceptions::PyException, prelude::*, types::PyList};
-use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyfunction, gen_stub_pymethods};
 use workflow_core::hex::ToHex;
 use zeroize::Zeroize;
 
@@ -101,6 +107,7 @@ pub fn py_create_input_signature(
 /// Args:
 ///     script_hash: The script hash to sign as a hex string.
 ///     privkey: The private key for signing.
+///     sighash_type: The signature hash type (default: All).
 ///
 /// Returns:
 ///     str: The signature as a hex string.
@@ -112,10 +119,17 @@ pub fn py_create_input_signature(
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "sign_script_hash")]
-pub fn py_sign_script_hash(script_hash: String, privkey: &PyPrivateKey) -> PyResult<String> {
+#[pyo3(signature = (script_hash, privkey, sighash_type=None))]
+pub fn py_sign_script_hash(
+    script_hash: String,
+    privkey: &PyPrivateKey,
+    #[gen_stub(override_type(type_repr = "str | SighashType | None = SighashType.All"))]
+    sighash_type: Option<PySighashType>,
+) -> PyResult<String> {
     let script_hash = PyHash::try_from(script_hash)?;
+    let sighash_type: SighashType = sighash_type.unwrap_or(PySighashType::All).into();
     let mut key_bytes = privkey.secret_bytes();
-    let result = sign_hash(script_hash.into(), &key_bytes)
+    let result = sign_hash(script_hash.into(), &key_bytes, sighash_type.into())
         .map_err(|err| PyException::new_err(err.to_string()))?;
     key_bytes.zeroize();
     Ok(result.to_hex())
@@ -142,13 +156,158 @@ fn sign<'a>(tx: &'a Transaction, privkeys: &[[u8; 32]]) -> Result<&'a Transactio
     Ok(sign_with_multiple_v3(tx, privkeys)?.unwrap())
 }
 
-fn sign_hash(sig_hash: Hash, privkey: &[u8; 32]) -> Result<Vec<u8>> {
+fn sign_hash(sig_hash: Hash, privkey: &[u8; 32], sighash_type: SigHashType) -> Result<Vec<u8>> {
     let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice())?;
     let schnorr_key = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, privkey)?;
     let sig: [u8; 64] = *schnorr_key.sign_schnorr(msg).as_ref();
     let signature = std::iter::once(65u8)
         .chain(sig)
-        .chain([SIG_HASH_ALL.to_u8()])
+        .chain([sighash_type.to_u8()])
         .collect();
     Ok(signature)
 }
+
+/// The outcome of trying to sign one transaction input with a specific key set.
+#[gen_stub_pyclass]
+#[pyclass(name = "InputSignResult")]
+#[derive(Clone)]
+pub struct PyInputSignResult {
+    /// The index of the input this result describes.
+    #[pyo3(get)]
+    input_index: usize,
+    /// Whether a matching key was found and the input was signed.
+    #[pyo3(get)]
+    signed: bool,
+    /// The hex-encoded public key that signed the input, if any.
+    #[pyo3(get)]
+    pubkey: Option<String>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyInputSignResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "InputSignResult(input_index={}, signed={}, pubkey={:?})",
+            self.input_index, self.signed, self.pubkey
+        )
+    }
+}
+
+/// Sign only the inputs whose locking script matches one of the supplied
+/// keys, instead of trying every key against every input like `sign_transaction`.
+///
+/// For each input, the candidate public key derived from each `signer` key
+/// is checked against that input's script public key: P2PK and P2PK-ECDSA
+/// match directly against the script's payload (via `ScriptClass`/address
+/// extraction), while P2SH matches against the pubkeys embedded in the
+/// corresponding entry of `redeem_scripts`.
+///
+/// Args:
+///     tx: The transaction to sign.
+///     signer: List of PrivateKey objects to try.
+///     redeem_scripts: Optional per-input hex-encoded redeem scripts, in
+///         the same order as `tx`'s inputs, for P2SH inputs. Use `None` at
+///         an input's position for non-P2SH inputs.
+///
+/// Returns:
+///     tuple[Transaction, list[InputSignResult]]: The transaction with
+///     every matched input signed, and a per-input report of whether it
+///     was signed and by which public key.
+///
+/// Raises:
+///     Exception: If the transaction's UTXOs cannot be resolved, or
+///         signing a matched input fails.
+///
+/// Category: Wallet/Transactions
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "sign_transaction_selective")]
+#[pyo3(signature = (tx, signer, redeem_scripts=None))]
+pub fn py_sign_transaction_selective(
+    mut tx: PyTransaction,
+    signer: Bound<'_, PyList>,
+    redeem_scripts: Option<Vec<Option<PyBinary>>>,
+) -> PyResult<(PyTransaction, Vec<PyInputSignResult>)> {
+    let mut candidates: Vec<([u8; 32], Vec<u8>, Option<Vec<u8>>)> = Vec::with_capacity(signer.len());
+    for item in signer.iter() {
+        let key: PyRef<'_, PyPrivateKey> = item.extract()?;
+        let public_key = key
+            .inner()
+            .to_public_key()
+            .map_err(|_| PyException::new_err("Failed to derive public key"))?;
+        let xonly = public_key.xonly_public_key.serialize().to_vec();
+        let full = public_key.public_key.map(|pk| pk.serialize().to_vec());
+        candidates.push((key.secret_bytes(), xonly, full));
+    }
+
+    let transaction: Transaction = tx.inner().clone();
+    let (cctx, utxos) = transaction
+        .tx_and_utxos()
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    let populated_transaction = PopulatedTransaction::new(&cctx, utxos.clone());
+
+    let redeem_scripts = redeem_scripts.unwrap_or_default();
+    let mut inputs = tx.get_inputs()?;
+    let mut results = Vec::with_capacity(utxos.len());
+
+    for (index, utxo) in utxos.iter().enumerate() {
+        let redeem_script = redeem_scripts.get(index).and_then(|script| script.as_ref());
+
+        let matched = if ScriptClass::is_pay_to_script_hash(utxo.script_public_key.script()) {
+            redeem_script.and_then(|redeem_script| {
+                candidates.iter().find(|(_, xonly, full)| {
+                    contains_subslice(&redeem_script.data, xonly)
+                        || full.as_ref().is_some_and(|full| contains_subslice(&redeem_script.data, full))
+                })
+            })
+        } else {
+            extract_script_pub_key_address(&utxo.script_public_key, Prefix::Mainnet)
+                .ok()
+                .and_then(|address| {
+                    candidates.iter().find(|(_, xonly, full)| {
+                        address.payload == *xonly || full.as_ref().is_some_and(|full| address.payload == *full)
+                    })
+                })
+        };
+
+        match matched {
+            Some((key_bytes, xonly, _full)) => {
+                let mut key_bytes = *key_bytes;
+                let signature = sign_input(&populated_transaction, index, &key_bytes, SIG_HASH_ALL);
+                key_bytes.zeroize();
+
+                let signature_script = if let Some(redeem_script) = redeem_script {
+                    standard::pay_to_script_hash_signature_script(redeem_script.data.clone(), signature)
+                        .map_err(|err| PyException::new_err(err.to_string()))?
+                } else {
+                    let mut builder = native::ScriptBuilder::new();
+                    builder
+                        .add_data(&signature)
+                        .map_err(|err| PyException::new_err(err.to_string()))?;
+                    builder.script().to_vec()
+                };
+
+                inputs[index].set_signature_script(PyBinary { data: signature_script });
+                results.push(PyInputSignResult {
+                    input_index: index,
+                    signed: true,
+                    pubkey: Some(xonly.to_hex()),
+                });
+            }
+            None => results.push(PyInputSignResult {
+                input_index: index,
+                signed: false,
+                pubkey: None,
+            }),
+        }
+    }
+
+    tx.set_inputs(inputs);
+    Ok((tx, results))
+}
+
+/// Whether `needle` occurs anywhere within `haystack`.
+fn contains_subslice(haystack: &[u8], needle: &[u8]) -> bool {
+    !needle.is_empty() && haystack.windows(needle.len()).any(|window| window == needle)
+}
@@ -2,8 +2,10 @@ use crate::consensus::client::transaction::PyTransaction;
 use crate::consensus::core::network::PyNetworkId;
 
 use super::super::imports::*;
+use super::error::PyInsufficientFundsError;
 use super::generator::{
-    PendingTransaction, PyGenerator, PyGeneratorSummary, PyOutputs, PyUtxoEntries,
+    PendingTransaction, PyCoinSelectionStrategy, PyGenerator, PyGeneratorSummary, PyOutputs,
+    PyUtxoEntries,
 };
 use kaspa_consensus_client::*;
 use kaspa_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
@@ -24,7 +26,9 @@ use pyo3_stub_gen::derive::gen_stub_pyfunction;
 ///     Transaction: The created transaction (unsigned).
 ///
 /// Raises:
-///     Exception: If transaction creation fails or fee exceeds input amount.
+///     InsufficientFundsError: If the input amount doesn't cover the outputs
+///     plus `priority_fee`.
+///     Exception: If transaction creation fails.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "create_transaction")]
@@ -60,10 +64,13 @@ pub fn py_create_transaction(
         })
         .collect::<Vec<TransactionInput>>();
 
-    if priority_fee > total_input_amount {
-        return Err(PyException::new_err(format!(
-            "priority fee({priority_fee}) > amount({total_input_amount})"
-        )));
+    let total_output_amount: u64 = outputs.outputs.iter().map(|output| output.amount).sum();
+    let required = total_output_amount.saturating_add(priority_fee);
+    if required > total_input_amount {
+        return Err(PyInsufficientFundsError::new_err(
+            total_input_amount,
+            required,
+        ));
     }
 
     let outputs = outputs
@@ -103,6 +110,8 @@ pub fn py_create_transaction(
 ///     priority_entries: UTXOs to use first.
 ///     sig_op_count: Signature operations per input (default: 1).
 ///     minimum_signatures: For multisig fee estimation.
+///     coin_selection: Coin-selection strategy - `"largest_first"` (default)
+///         or `"random_improve"`.
 ///
 /// Returns:
 ///     dict: Dictionary with "transactions" (list) and "summary" keys.
@@ -112,7 +121,7 @@ pub fn py_create_transaction(
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "create_transactions")]
-#[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None))]
+#[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None, coin_selection=None))]
 pub fn py_create_transactions<'a>(
     py: Python<'a>,
     #[gen_stub(override_type(type_repr = "UtxoEntries | UtxoContext"))] entries: Bound<'_, PyAny>,
@@ -125,6 +134,8 @@ pub fn py_create_transactions<'a>(
     priority_entries: Option<PyUtxoEntries>,
     sig_op_count: Option<u8>,
     minimum_signatures: Option<u16>,
+    #[gen_stub(override_type(type_repr = "str | CoinSelectionStrategy | None = CoinSelectionStrategy.LargestFirst"))]
+    coin_selection: Option<PyCoinSelectionStrategy>,
 ) -> PyResult<Bound<'a, PyDict>> {
     let generator = PyGenerator::ctor(
         entries,
@@ -137,6 +148,7 @@ pub fn py_create_transactions<'a>(
         priority_entries,
         sig_op_count,
         minimum_signatures,
+        coin_selection,
     )?;
 
     let transactions = generator
@@ -164,6 +176,8 @@ pub fn py_create_transactions<'a>(
 ///     priority_entries: UTXOs to use first.
 ///     sig_op_count: Signature operations per input (default: 1).
 ///     minimum_signatures: For multisig fee estimation.
+///     coin_selection: Coin-selection strategy - `"largest_first"` (default)
+///         or `"random_improve"`.
 ///
 /// Returns:
 ///     GeneratorSummary: Summary with fee, transaction count, and other details.
@@ -173,7 +187,7 @@ pub fn py_create_transactions<'a>(
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "estimate_transactions")]
-#[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None))]
+#[pyo3(signature = (entries, change_address, network_id=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None, coin_selection=None))]
 pub fn py_estimate_transactions(
     #[gen_stub(override_type(type_repr = "UtxoEntries | UtxoContext"))] entries: Bound<'_, PyAny>,
     change_address: PyAddress,
@@ -185,6 +199,8 @@ pub fn py_estimate_transactions(
     priority_entries: Option<PyUtxoEntries>,
     sig_op_count: Option<u8>,
     minimum_signatures: Option<u16>,
+    #[gen_stub(override_type(type_repr = "str | CoinSelectionStrategy | None = CoinSelectionStrategy.LargestFirst"))]
+    coin_selection: Option<PyCoinSelectionStrategy>,
 ) -> PyResult<PyGeneratorSummary> {
     let generator = PyGenerator::ctor(
         entries,
@@ -197,6 +213,7 @@ pub fn py_estimate_transactions(
         priority_entries,
         sig_op_count,
         minimum_signatures,
+        coin_selection,
     )?;
 
     generator
@@ -1,8 +1,11 @@
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyException, prelude::*};
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
 
 use crate::consensus::core::network::PyNetworkType;
 
+const SOMPI_PER_KASPA: u64 = 100_000_000;
+const SOMPI_DECIMALS: usize = 8;
+
 /// Convert KAS to sompi (1 KAS = 100,000,000 sompi).
 ///
 /// Args:
@@ -31,6 +34,81 @@ pub fn py_sompi_to_kaspa(sompi: u64) -> f64 {
     kaspa_wallet_core::utils::sompi_to_kaspa(sompi)
 }
 
+/// Parse a decimal KAS amount string into sompi using integer arithmetic only.
+///
+/// Unlike `kaspa_to_sompi`, this never touches `f64`, so it doesn't silently
+/// lose precision on large or fractional amounts.
+///
+/// Args:
+///     amount: A decimal KAS amount string, e.g. `"1.23456789"`.
+///
+/// Returns:
+///     int: The amount in sompi.
+///
+/// Raises:
+///     Exception: If `amount` is not a valid decimal number, has more than
+///     8 fractional digits, or the result overflows `u64`.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "try_kaspa_str_to_sompi")]
+pub fn py_try_kaspa_str_to_sompi(amount: &str) -> PyResult<u64> {
+    let trimmed = amount.trim();
+    let (integer_part, fractional_part) = match trimmed.split_once('.') {
+        Some((integer, fractional)) => (integer, fractional),
+        None => (trimmed, ""),
+    };
+
+    if fractional_part.len() > SOMPI_DECIMALS {
+        return Err(PyException::new_err(format!(
+            "KAS amount `{amount}` has more than {SOMPI_DECIMALS} decimal places"
+        )));
+    }
+
+    let integer_value: u64 = if integer_part.is_empty() {
+        0
+    } else {
+        integer_part
+            .parse()
+            .map_err(|_| PyException::new_err(format!("Invalid KAS amount: `{amount}`")))?
+    };
+
+    let padded_fractional = format!("{fractional_part:0<width$}", width = SOMPI_DECIMALS);
+    let fractional_value: u64 = padded_fractional
+        .parse()
+        .map_err(|_| PyException::new_err(format!("Invalid KAS amount: `{amount}`")))?;
+
+    integer_value
+        .checked_mul(SOMPI_PER_KASPA)
+        .and_then(|sompi| sompi.checked_add(fractional_value))
+        .ok_or_else(|| PyException::new_err(format!("KAS amount `{amount}` overflows u64 sompi")))
+}
+
+/// Format a sompi amount as an exact decimal KAS string, without floating point.
+///
+/// Trailing zeroes in the fractional part are trimmed, and whole amounts are
+/// rendered with no decimal point at all.
+///
+/// Args:
+///     sompi: The amount in sompi.
+///
+/// Returns:
+///     str: The amount formatted in KAS.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "sompi_to_kaspa_str")]
+pub fn py_sompi_to_kaspa_str(sompi: u64) -> String {
+    let integer_part = sompi / SOMPI_PER_KASPA;
+    let fractional_part = sompi % SOMPI_PER_KASPA;
+
+    if fractional_part == 0 {
+        integer_part.to_string()
+    } else {
+        let fractional_str = format!("{fractional_part:0width$}", width = SOMPI_DECIMALS);
+        let fractional_str = fractional_str.trim_end_matches('0');
+        format!("{integer_part}.{fractional_str}")
+    }
+}
+
 /// Convert sompi to a formatted KAS string with network suffix.
 ///
 /// Args:
@@ -3,14 +3,14 @@ use crate::consensus::core::network::PyNetworkId;
 use crate::rpc::wrpc::client::PyRpcClient;
 use ahash::AHashMap;
 use futures::*;
-use kaspa_wallet_core::events::EventKind;
+use kaspa_wallet_core::events::{EventKind, Events};
 use kaspa_wallet_core::rpc::{DynRpcApi, Rpc};
 use kaspa_wallet_core::utxo::{
     UtxoProcessor, set_coinbase_transaction_maturity_period_daa,
     set_user_transaction_maturity_period_daa,
 };
 use pyo3::{
-    exceptions::PyException,
+    exceptions::{PyException, PyStopAsyncIteration},
     prelude::*,
     types::{PyDict, PyTuple},
 };
@@ -20,12 +20,24 @@ use std::{
     str::FromStr,
     sync::{
         Arc, Mutex,
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicU64, Ordering},
     },
+    time::{Duration, Instant},
 };
-use workflow_core::channel::DuplexChannel;
+use workflow_core::channel::{Channel, DuplexChannel, Receiver, Sender};
 use workflow_log::*;
 
+/// How often the notification task polls coalesced events for a due flush.
+const COALESCE_TICK: Duration = Duration::from_millis(25);
+
+/// A registered event listener: either a Python callback or the sender half
+/// of an async-iterator stream (see [`PyUtxoProcessor::stream`]).
+#[derive(Clone)]
+enum Listener {
+    Callback(PyCallback),
+    Stream(u64, Sender<Py<PyDict>>),
+}
+
 /// Event types for `UtxoProcessor` listeners.
 #[gen_stub_pyclass_enum]
 #[pyclass(name = "UtxoProcessorEvent", skip_from_py_object, eq)]
@@ -99,9 +111,13 @@ impl From<PyUtxoProcessorEvent> for EventKind {
 pub struct PyUtxoProcessor {
     processor: UtxoProcessor,
     rpc: PyRpcClient,
-    callbacks: Arc<Mutex<AHashMap<EventKind, Vec<PyCallback>>>>,
+    callbacks: Arc<Mutex<AHashMap<EventKind, Vec<Listener>>>>,
     notification_task: Arc<AtomicBool>,
     notification_ctl: DuplexChannel,
+    sync_ctl: DuplexChannel,
+    coalesce_intervals: Arc<Mutex<AHashMap<EventKind, Duration>>>,
+    coalesce_pending: Arc<Mutex<AHashMap<EventKind, (Instant, Arc<Events>)>>>,
+    stream_id: Arc<AtomicU64>,
 }
 
 impl PyUtxoProcessor {
@@ -142,7 +158,108 @@ impl PyUtxoProcessor {
         Ok(())
     }
 
-    fn notification_callbacks(&self, event: EventKind) -> Option<Vec<PyCallback>> {
+    fn dispatch_to_listeners(&self, event_type: EventKind, notification: &Arc<Events>) {
+        if let Some(handlers) = self.notification_callbacks(event_type) {
+            for handler in handlers.into_iter() {
+                if let Err(err) = Python::attach(|py| -> PyResult<()> {
+                    let event_any = match serde_pyobject::to_pyobject(py, notification.as_ref()) {
+                        Ok(obj) => obj,
+                        Err(err) => {
+                            log_error!(
+                                "UtxoProcessor: failed to serialize event `{}`: {}",
+                                event_type,
+                                err
+                            );
+                            return Ok(());
+                        }
+                    };
+
+                    let event = match event_any.cast::<PyDict>() {
+                        Ok(dict) => dict,
+                        Err(err) => {
+                            log_error!(
+                                "UtxoProcessor: serialized event `{}` is not a dict: {}",
+                                event_type,
+                                err
+                            );
+                            return Ok(());
+                        }
+                    };
+
+                    if let Err(err) = Self::normalize_event_payload(py, event_type, event) {
+                        log_error!(
+                            "UtxoProcessor: failed to normalize event payload for `{}`: {}",
+                            event_type,
+                            err
+                        );
+                    }
+
+                    match handler {
+                        Listener::Callback(callback) => {
+                            if callback.matches(py, event) {
+                                if let Err(err) = callback.execute(py, (*event).clone()) {
+                                    log_error!(
+                                        "UtxoProcessor: error while executing event listener for `{}`: {}",
+                                        event_type,
+                                        err
+                                    );
+                                }
+                            }
+                        }
+                        Listener::Stream(_, sender) => {
+                            if sender.try_send(event.clone().unbind()).is_err() {
+                                log_error!(
+                                    "UtxoProcessor: failed to push event `{}` into a closed stream",
+                                    event_type
+                                );
+                            }
+                        }
+                    }
+
+                    Ok(())
+                }) {
+                    log_error!(
+                        "UtxoProcessor: error while building event payload for `{}`: {}",
+                        event_type,
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    /// Flush every coalesced event whose debounce window has elapsed.
+    fn flush_due_coalesced(&self) {
+        let due: Vec<(EventKind, Arc<Events>)> = {
+            let intervals = self.coalesce_intervals.lock().unwrap();
+            let mut pending = self.coalesce_pending.lock().unwrap();
+            let now = Instant::now();
+            let ready: Vec<EventKind> = pending
+                .iter()
+                .filter(|(kind, (since, _))| {
+                    intervals
+                        .get(kind)
+                        .map(|interval| now.duration_since(*since) >= *interval)
+                        .unwrap_or(true)
+                })
+                .map(|(kind, _)| *kind)
+                .collect();
+            ready
+                .into_iter()
+                .filter_map(|kind| {
+                    pending
+                        .remove(&kind)
+                        .map(|(_, notification)| (kind, notification))
+                })
+                .collect()
+        };
+
+        for (event_type, notification) in due {
+            self.dispatch_to_listeners(event_type, &notification);
+        }
+    }
+
+    fn notification_callbacks(&self, event: EventKind) -> Option<Vec<Listener>> {
         let notification_callbacks = self.callbacks.lock().unwrap();
         let all = notification_callbacks.get(&EventKind::All).cloned();
         let target = notification_callbacks.get(&event).cloned();
@@ -168,11 +285,15 @@ impl PyUtxoProcessor {
 
         let ctl_receiver = self.notification_ctl.request.receiver.clone();
         let ctl_sender = self.notification_ctl.response.sender.clone();
+        let sync_receiver = self.sync_ctl.request.receiver.clone();
+        let sync_sender = self.sync_ctl.response.sender.clone();
         let channel = self.processor.multiplexer().channel();
         let this = self.clone();
 
         let fut = async move {
             let mut shutdown_requested = false;
+            let mut coalesce_ticker = tokio::time::interval(COALESCE_TICK);
+
             loop {
                 if shutdown_requested && channel.receiver.is_empty() {
                     break;
@@ -182,58 +303,38 @@ impl PyUtxoProcessor {
                     _ = ctl_receiver.recv().fuse() => {
                         shutdown_requested = true;
                     }
+                    msg = sync_receiver.recv().fuse() => {
+                        if msg.is_ok() {
+                            while let Ok(notification) = channel.receiver.try_recv() {
+                                let event_type = EventKind::from(notification.as_ref());
+                                this.dispatch_to_listeners(event_type, &notification);
+                            }
+                            this.flush_due_coalesced();
+                            sync_sender.send(()).await.ok();
+                        }
+                    }
+                    _ = coalesce_ticker.tick().fuse() => {
+                        this.flush_due_coalesced();
+                    }
                     msg = channel.receiver.recv().fuse() => {
                         match msg {
                             Ok(notification) => {
                                 let event_type = EventKind::from(notification.as_ref());
-                                if let Some(handlers) = this.notification_callbacks(event_type) {
-                                    for handler in handlers.into_iter() {
-                                        if let Err(err) = Python::attach(|py| -> PyResult<()> {
-                                            let event_any = match serde_pyobject::to_pyobject(py, notification.as_ref()) {
-                                                Ok(obj) => obj,
-                                                Err(err) => {
-                                                    log_error!("UtxoProcessor: failed to serialize event `{}`: {}", event_type, err);
-                                                    return Ok(());
-                                                }
-                                            };
-
-                                            let event = match event_any.cast::<PyDict>() {
-                                                Ok(dict) => dict,
-                                                Err(err) => {
-                                                    log_error!(
-                                                        "UtxoProcessor: serialized event `{}` is not a dict: {}",
-                                                        event_type,
-                                                        err
-                                                    );
-                                                    return Ok(());
-                                                }
-                                            };
-
-                                            if let Err(err) = Self::normalize_event_payload(py, event_type, event) {
-                                                log_error!(
-                                                    "UtxoProcessor: failed to normalize event payload for `{}`: {}",
-                                                    event_type,
-                                                    err
-                                                );
-                                            }
-
-                                            if let Err(err) = handler.execute(py, (*event).clone()) {
-                                                log_error!(
-                                                    "UtxoProcessor: error while executing event listener for `{}`: {}",
-                                                    event_type,
-                                                    err
-                                                );
-                                            }
-
-                                            Ok(())
-                                        }) {
-                                            log_error!(
-                                                "UtxoProcessor: error while building event payload for `{}`: {}",
-                                                event_type,
-                                                err
-                                            );
-                                        }
-                                    }
+                                let coalesce_interval = this
+                                    .coalesce_intervals
+                                    .lock()
+                                    .unwrap()
+                                    .get(&event_type)
+                                    .copied();
+
+                                if coalesce_interval.is_some() {
+                                    let mut pending = this.coalesce_pending.lock().unwrap();
+                                    pending
+                                        .entry(event_type)
+                                        .and_modify(|(_, existing)| *existing = notification.clone())
+                                        .or_insert_with(|| (Instant::now(), notification));
+                                } else {
+                                    this.dispatch_to_listeners(event_type, &notification);
                                 }
                             }
                             Err(err) => {
@@ -292,6 +393,10 @@ impl PyUtxoProcessor {
             callbacks: Arc::new(Mutex::new(Default::default())),
             notification_task: Arc::new(AtomicBool::new(false)),
             notification_ctl: DuplexChannel::oneshot(),
+            sync_ctl: DuplexChannel::oneshot(),
+            coalesce_intervals: Arc::new(Mutex::new(Default::default())),
+            coalesce_pending: Arc::new(Mutex::new(Default::default())),
+            stream_id: Arc::new(AtomicU64::new(0)),
         })
     }
 
@@ -378,6 +483,7 @@ impl PyUtxoProcessor {
     ///     event_or_callback: Event target as string (kebab-case), `UtxoProcessorEvent` variant, a list of those, "*" / "all", or a callback (listen to all events).
     ///     callback: Function to call when event occurs (required when event_or_callback is an event target).
     ///     *args: Additional arguments to pass to callback.
+    ///     filter: Optional predicate `(event: dict) -> bool` gating delivery; the callback only runs when it returns truthy.
     ///     **kwargs: Additional keyword arguments to pass to callback.
     ///
     /// Returns:
@@ -386,13 +492,14 @@ impl PyUtxoProcessor {
     /// Notes:
     ///     Callback will be invoked as: callback(*args, event, **kwargs)
     ///     Where event is a dict like: {"type": str, "data": ...}
-    #[pyo3(signature = (event_or_callback, callback=None, *args, **kwargs))]
+    #[pyo3(signature = (event_or_callback, callback=None, *args, filter=None, **kwargs))]
     fn add_event_listener(
         &self,
         py: Python,
         event_or_callback: Bound<'_, PyAny>,
         callback: Option<Py<PyAny>>,
         args: &Bound<'_, PyTuple>,
+        filter: Option<Py<PyAny>>,
         kwargs: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
         let (targets, callback) = match callback {
@@ -417,14 +524,11 @@ impl PyUtxoProcessor {
             None => PyDict::new(py).into(),
         };
 
-        let py_callback = PyCallback::new(callback, args, kwargs);
+        let listener = Listener::Callback(PyCallback::new(callback, args, kwargs).with_filter(filter));
 
         let mut callbacks = self.callbacks.lock().unwrap();
         for target in targets {
-            callbacks
-                .entry(target)
-                .or_default()
-                .push(py_callback.clone());
+            callbacks.entry(target).or_default().push(listener.clone());
         }
         Ok(())
     }
@@ -448,7 +552,7 @@ impl PyUtxoProcessor {
         if callback.is_none() && event_or_callback.is_callable() {
             let callback = event_or_callback.extract::<Py<PyAny>>()?;
             for handlers in callbacks.values_mut() {
-                handlers.retain(|entry| !entry.callback_ptr_eq(&callback));
+                handlers.retain(|entry| !listener_matches_callback(entry, &callback));
             }
             return Ok(());
         }
@@ -459,7 +563,7 @@ impl PyUtxoProcessor {
             Some(callback) => {
                 for target in targets {
                     if let Some(handlers) = callbacks.get_mut(&target) {
-                        handlers.retain(|entry| !entry.callback_ptr_eq(&callback));
+                        handlers.retain(|entry| !listener_matches_callback(entry, &callback));
                     }
                 }
             }
@@ -481,6 +585,147 @@ impl PyUtxoProcessor {
         self.callbacks.lock().unwrap().clear();
         Ok(())
     }
+
+    /// Coalesce a high-frequency event kind, delivering only the most recent
+    /// occurrence at most once per `interval_ms`.
+    ///
+    /// Args:
+    ///     event: Event target as string (kebab-case) or `UtxoProcessorEvent` variant.
+    ///     interval_ms: Minimum time between deliveries of this event kind, in milliseconds.
+    ///
+    /// Returns:
+    ///     None
+    fn set_event_coalescing(&self, event: Bound<'_, PyAny>, interval_ms: u64) -> PyResult<()> {
+        let event_kind = parse_event_target_item(&event)?;
+        self.coalesce_intervals
+            .lock()
+            .unwrap()
+            .insert(event_kind, Duration::from_millis(interval_ms));
+        Ok(())
+    }
+
+    /// Wait until every notification already queued at call time has been
+    /// dispatched to listeners (async).
+    ///
+    /// Resolves immediately if the notification task is not running.
+    #[gen_stub(override_return_type(type_repr = "None"))]
+    fn sync<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let running = self.notification_task.load(Ordering::SeqCst);
+        let sync_ctl = self.sync_ctl.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            if running {
+                sync_ctl
+                    .signal(())
+                    .await
+                    .map_err(|err| PyException::new_err(err.to_string()))?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Get an async iterator over UtxoProcessor events, as an alternative to
+    /// callback-based `add_event_listener`.
+    ///
+    /// Args:
+    ///     event_or_callback: Event target as string (kebab-case), `UtxoProcessorEvent` variant, a list of those, "*" / "all", or None (listen to all events).
+    ///
+    /// Returns:
+    ///     UtxoEventStream: An async iterator yielding `{"type", "data"}` dicts.
+    #[pyo3(signature = (event_or_callback=None))]
+    fn stream(&self, event_or_callback: Option<Bound<'_, PyAny>>) -> PyResult<PyUtxoEventStream> {
+        let targets = match event_or_callback {
+            Some(value) => parse_event_targets(value)?,
+            None => vec![EventKind::All],
+        };
+
+        let id = self.stream_id.fetch_add(1, Ordering::SeqCst);
+        let channel = Channel::unbounded();
+        let listener = Listener::Stream(id, channel.sender.clone());
+
+        let mut callbacks = self.callbacks.lock().unwrap();
+        for target in &targets {
+            callbacks.entry(*target).or_default().push(listener.clone());
+        }
+        drop(callbacks);
+
+        Ok(PyUtxoEventStream {
+            receiver: channel.receiver,
+            callbacks: self.callbacks.clone(),
+            targets,
+            id,
+            closed: AtomicBool::new(false),
+        })
+    }
+}
+
+/// An async iterator over `UtxoProcessor` events, yielded as `{"type", "data"}`
+/// dicts matching the shape passed to `add_event_listener` callbacks.
+///
+/// Returned by [`PyUtxoProcessor::stream`] for consumers that prefer
+/// `async for event in processor.stream(...)` over registering a callback.
+/// Unregisters its sender from the processor's listener table when the
+/// iterator is garbage-collected or explicitly closed via `aclose()`.
+#[gen_stub_pyclass]
+#[pyclass(name = "UtxoEventStream")]
+struct PyUtxoEventStream {
+    receiver: Receiver<Py<PyDict>>,
+    callbacks: Arc<Mutex<AHashMap<EventKind, Vec<Listener>>>>,
+    targets: Vec<EventKind>,
+    id: u64,
+    closed: AtomicBool,
+}
+
+impl PyUtxoEventStream {
+    fn unregister(&self) {
+        if self.closed.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let mut callbacks = self.callbacks.lock().unwrap();
+        for target in &self.targets {
+            if let Some(handlers) = callbacks.get_mut(target) {
+                handlers.retain(|entry| !matches!(entry, Listener::Stream(id, _) if *id == self.id));
+            }
+        }
+    }
+}
+
+impl Drop for PyUtxoEventStream {
+    fn drop(&mut self) {
+        self.unregister();
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyUtxoEventStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let receiver = self.receiver.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            match receiver.recv().await {
+                Ok(event) => Ok(event),
+                Err(_) => Err(PyStopAsyncIteration::new_err(
+                    "UtxoProcessor event stream has been closed",
+                )),
+            }
+        })
+    }
+
+    /// Unregister this stream's sender and stop yielding further events.
+    fn aclose(&self) -> PyResult<()> {
+        self.unregister();
+        Ok(())
+    }
+}
+
+fn listener_matches_callback(listener: &Listener, callback: &Py<PyAny>) -> bool {
+    match listener {
+        Listener::Callback(entry) => entry.callback_ptr_eq(callback),
+        Listener::Stream(..) => false,
+    }
 }
 
 fn parse_event_targets(value: Bound<'_, PyAny>) -> PyResult<Vec<EventKind>> {
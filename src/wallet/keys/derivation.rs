@@ -13,6 +13,16 @@ use std::str::FromStr;
 #[derive(Clone, PartialEq)]
 pub struct PyDerivationPath(kaspa_bip32::DerivationPath);
 
+impl PyDerivationPath {
+    fn child(&self, index: u32, hardened: bool) -> PyResult<PyDerivationPath> {
+        let child = ChildNumber::new(index, hardened)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let mut path = self.0.clone();
+        path.push(child);
+        Ok(PyDerivationPath(path))
+    }
+}
+
 #[gen_stub_pymethods]
 #[pymethods]
 impl PyDerivationPath {
@@ -74,6 +84,60 @@ impl PyDerivationPath {
         Ok(())
     }
 
+    /// Derive a contiguous run of child paths.
+    ///
+    /// Args:
+    ///     start: The first child index in the range.
+    ///     count: Number of consecutive child paths to derive.
+    ///     hardened: Whether to use hardened derivation (default: False).
+    ///
+    /// Returns:
+    ///     list[DerivationPath]: `count` consecutive child paths starting at `start`.
+    ///
+    /// Raises:
+    ///     Exception: If `start + count` overflows, or any index in the range is not a valid child number.
+    #[pyo3(signature = (start, count, hardened=None))]
+    pub fn derive_range(
+        &self,
+        start: u32,
+        count: u32,
+        hardened: Option<bool>,
+    ) -> PyResult<Vec<PyDerivationPath>> {
+        let hardened = hardened.unwrap_or(false);
+        (0..count)
+            .map(|offset| {
+                let index = start
+                    .checked_add(offset)
+                    .ok_or_else(|| PyException::new_err("Child index overflow while deriving range"))?;
+                self.child(index, hardened)
+            })
+            .collect()
+    }
+
+    /// Derive child paths at an explicit sequence of indices.
+    ///
+    /// Args:
+    ///     indices: The child indices to derive.
+    ///     hardened: Whether to use hardened derivation (default: False).
+    ///
+    /// Returns:
+    ///     list[DerivationPath]: One child path per entry in `indices`.
+    ///
+    /// Raises:
+    ///     Exception: If any index is not a valid child number.
+    #[pyo3(signature = (indices, hardened=None))]
+    pub fn children(
+        &self,
+        indices: Vec<u32>,
+        hardened: Option<bool>,
+    ) -> PyResult<Vec<PyDerivationPath>> {
+        let hardened = hardened.unwrap_or(false);
+        indices
+            .into_iter()
+            .map(|index| self.child(index, hardened))
+            .collect()
+    }
+
     /// Convert to string representation.
     ///
     /// Returns:
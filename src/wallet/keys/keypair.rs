@@ -1,13 +1,24 @@
 use super::privatekey::PyPrivateKey;
-use crate::{address::PyAddress, consensus::core::network::PyNetworkType};
+use super::publickey::PyPublicKey;
+use crate::{address::PyAddress, consensus::core::network::PyNetworkType, types::PyBinary};
+use aes_gcm::{
+    Aes256Gcm, Key, Nonce,
+    aead::{Aead, KeyInit},
+};
 use kaspa_addresses::{Address, Version};
 use kaspa_consensus_core::network::NetworkType;
+use kaspa_wallet_core::message::{PersonalMessage, SignMessageOptions, sign_message, verify_message};
 use kaspa_wallet_keys::{privatekey::PrivateKey, publickey::PublicKey};
-use pyo3::{exceptions::PyException, prelude::*};
+use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use rand::rngs::OsRng;
+use sha2::{Digest, Sha256};
 use std::str::FromStr;
 use zeroize::Zeroize;
 
+/// Length, in bytes, of the random nonce prepended to `Keypair.encrypt` ciphertexts.
+const NONCE_LEN: usize = 12;
+
 /// A cryptographic keypair containing private and public keys.
 ///
 /// Provides convenient access to all key forms needed for signing
@@ -159,4 +170,167 @@ impl PyKeypair {
             xonly_public_key,
         })
     }
+
+    /// Compute the ECDH shared secret with a counterparty's public key, as
+    /// SHA-256 of the compressed shared point.
+    ///
+    /// Args:
+    ///     other_public_key: The counterparty's public key.
+    ///
+    /// Returns:
+    ///     bytes: The 32-byte shared secret.
+    ///
+    /// Raises:
+    ///     Exception: If `other_public_key` has no full (non x-only) public
+    ///         key, or the ECDH computation fails.
+    pub fn shared_secret<'py>(
+        &self,
+        py: Python<'py>,
+        other_public_key: &PyPublicKey,
+    ) -> PyResult<Bound<'py, PyBytes>> {
+        let secret = self.compute_shared_secret(other_public_key)?;
+        Ok(PyBytes::new(py, &secret))
+    }
+
+    /// Encrypt `plaintext` to a counterparty's public key using the
+    /// ECDH shared secret (see `shared_secret`) as an AES-256-GCM key,
+    /// with a random 12-byte nonce prepended to the ciphertext.
+    ///
+    /// Args:
+    ///     other_public_key: The counterparty's public key.
+    ///     plaintext: The bytes to encrypt.
+    ///
+    /// Returns:
+    ///     bytes: `nonce (12 bytes) || ciphertext`, ready to pass to `decrypt`.
+    ///
+    /// Raises:
+    ///     Exception: If `other_public_key` is invalid or encryption fails.
+    pub fn encrypt<'py>(
+        &self,
+        py: Python<'py>,
+        other_public_key: &PyPublicKey,
+        plaintext: PyBinary,
+    ) -> PyResult<Bound<'py, PyBytes>> {
+        let key = self.compute_shared_secret(other_public_key)?;
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        rand::RngCore::fill_bytes(&mut OsRng, &mut nonce_bytes);
+        let nonce = Nonce::from_slice(&nonce_bytes);
+
+        let ciphertext = cipher
+            .encrypt(nonce, plaintext.data.as_slice())
+            .map_err(|err| PyException::new_err(format!("Encryption failed: {err}")))?;
+
+        let mut output = nonce_bytes.to_vec();
+        output.extend_from_slice(&ciphertext);
+        Ok(PyBytes::new(py, &output))
+    }
+
+    /// Decrypt a ciphertext produced by `encrypt` from a counterparty's
+    /// public key.
+    ///
+    /// Args:
+    ///     other_public_key: The counterparty's public key.
+    ///     ciphertext: The encoded blob produced by `encrypt`.
+    ///
+    /// Returns:
+    ///     bytes: The recovered plaintext.
+    ///
+    /// Raises:
+    ///     Exception: If the blob is malformed, `other_public_key` does not
+    ///         match, or authentication fails.
+    pub fn decrypt<'py>(
+        &self,
+        py: Python<'py>,
+        other_public_key: &PyPublicKey,
+        ciphertext: PyBinary,
+    ) -> PyResult<Bound<'py, PyBytes>> {
+        let key = self.compute_shared_secret(other_public_key)?;
+
+        let data = &ciphertext.data;
+        if data.len() < NONCE_LEN {
+            return Err(PyException::new_err(
+                "ciphertext is too short to contain a nonce",
+            ));
+        }
+        let (nonce_bytes, encrypted) = data.split_at(NONCE_LEN);
+
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+        let nonce = Nonce::from_slice(nonce_bytes);
+
+        let plaintext = cipher
+            .decrypt(nonce, encrypted)
+            .map_err(|_| PyException::new_err("Decryption failed: wrong key or corrupted ciphertext"))?;
+
+        Ok(PyBytes::new(py, &plaintext))
+    }
+
+    /// Sign a message with this keypair, proving ownership of the
+    /// corresponding address without spending any funds.
+    ///
+    /// Builds on the same `PersonalMessage`/Schnorr signing the module-level
+    /// `sign_message`/`verify_message` functions use, so application login
+    /// challenges and order authorizations can be signed straight off a
+    /// `Keypair` without separately extracting its private key.
+    ///
+    /// Args:
+    ///     message: The message to sign.
+    ///     no_aux_rand: If True, use deterministic signing (default: False).
+    ///
+    /// Returns:
+    ///     str: The signature as a hex string.
+    ///
+    /// Raises:
+    ///     Exception: If signing fails.
+    #[pyo3(signature = (message, no_aux_rand=false))]
+    pub fn sign_message(&self, message: &str, no_aux_rand: bool) -> PyResult<String> {
+        let pm = PersonalMessage(message);
+        let sign_options = SignMessageOptions { no_aux_rand };
+        let mut privkey_bytes = self.secret_key.secret_bytes();
+        let sig_vec = sign_message(&pm, &privkey_bytes, &sign_options)
+            .map_err(|err| PyException::new_err(format!("{}", err)))?;
+        privkey_bytes.zeroize();
+        Ok(faster_hex::hex_string(sig_vec.as_slice()))
+    }
+
+    /// Verify a signature produced by `sign_message`.
+    ///
+    /// Args:
+    ///     message: The original message.
+    ///     signature: The signature hex string.
+    ///     public_key: The public key to verify against.
+    ///
+    /// Returns:
+    ///     bool: True if the signature is valid, False otherwise.
+    ///
+    /// Raises:
+    ///     Exception: If the signature format is invalid.
+    #[staticmethod]
+    pub fn verify_message(message: &str, signature: &str, public_key: &PyPublicKey) -> PyResult<bool> {
+        let pm = PersonalMessage(message);
+        let mut signature_bytes = [0u8; 64];
+        faster_hex::hex_decode(signature.as_bytes(), &mut signature_bytes)
+            .map_err(|err| PyException::new_err(format!("{}", err)))?;
+
+        Ok(verify_message(&pm, &signature_bytes.to_vec(), &public_key.0.xonly_public_key).is_ok())
+    }
+}
+
+impl PyKeypair {
+    /// Shared implementation behind `shared_secret`, `encrypt`, and `decrypt`.
+    fn compute_shared_secret(&self, other_public_key: &PyPublicKey) -> PyResult<[u8; 32]> {
+        let other = other_public_key
+            .0
+            .public_key
+            .ok_or_else(|| PyException::new_err("shared_secret requires a full (non x-only) public key"))?;
+
+        let shared_point = other
+            .mul_tweak(secp256k1::SECP256K1, &secp256k1::Scalar::from(self.secret_key))
+            .map_err(|err| PyException::new_err(format!("ECDH failed: {err}")))?;
+
+        let mut secret = [0u8; 32];
+        secret.copy_from_slice(&Sha256::digest(shared_point.serialize()));
+        Ok(secret)
+    }
 }
@@ -1,6 +1,7 @@
 use super::publickey::PyPublicKey;
 use crate::{
-    address::PyAddress, consensus::core::network::PyNetworkType, wallet::keys::keypair::PyKeypair,
+    address::PyAddress, consensus::core::network::PyNetworkType, crypto::keyfile,
+    wallet::keys::keypair::PyKeypair,
 };
 use kaspa_addresses::{Address, Version};
 use kaspa_consensus_core::network::NetworkType;
@@ -135,6 +136,40 @@ impl PyPrivateKey {
     pub fn to_keypair(&self) -> PyResult<PyKeypair> {
         PyKeypair::from_private_key(self).map_err(|err| PyException::new_err(err.to_string()))
     }
+
+    /// Save this key to a file.
+    ///
+    /// Args:
+    ///     path: The file path to write to.
+    ///     password: Optional password; if given, the file is encrypted
+    ///         with AES-256-GCM under a PBKDF2-HMAC-SHA512-derived key.
+    ///         Otherwise the hex string is stored in plain text.
+    ///
+    /// Raises:
+    ///     Exception: If serialization or writing the file fails.
+    #[pyo3(signature = (path, password=None))]
+    pub fn write_to_file(&self, path: &str, password: Option<&str>) -> PyResult<()> {
+        keyfile::write_to_file(path, "PrivateKey", &self.to_hex(), password)
+    }
+
+    /// Load a key previously saved with `write_to_file`.
+    ///
+    /// Args:
+    ///     path: The file path to read from.
+    ///     password: The password, if the file is encrypted.
+    ///
+    /// Returns:
+    ///     PrivateKey: The loaded private key.
+    ///
+    /// Raises:
+    ///     Exception: If the file is missing, not a `PrivateKey` key file, or
+    ///         `password` is required/wrong.
+    #[staticmethod]
+    #[pyo3(signature = (path, password=None))]
+    pub fn read_from_file(path: &str, password: Option<&str>) -> PyResult<PyPrivateKey> {
+        let key = keyfile::read_from_file(path, "PrivateKey", password)?;
+        PyPrivateKey::try_new(&key)
+    }
 }
 
 impl From<PyPrivateKey> for PrivateKey {
@@ -4,10 +4,12 @@ use kaspa_wallet_core::derivation::WalletDerivationManagerTrait;
 use kaspa_wallet_keys::publickey::PublicKey;
 use kaspa_wallet_keys::result::Result;
 use kaspa_wallet_keys::{derivation::gen1::WalletDerivationManager, xpub::XPub};
-use pyo3::{exceptions::PyException, prelude::*};
+use pyo3::{exceptions::PyException, prelude::*, types::PyDict};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use workflow_core::hex::ToHex;
 
 use crate::consensus::core::network::PyNetworkType;
+use crate::wallet::core::descriptor::descriptor_checksum;
 use crate::wallet::keys::xprv::PyXPrv;
 use crate::{address::PyAddress, wallet::keys::publickey::PyPublicKey};
 
@@ -22,6 +24,12 @@ use crate::{address::PyAddress, wallet::keys::publickey::PyPublicKey};
 #[derive(Clone)]
 pub struct PyPublicKeyGenerator {
     hd_wallet: WalletDerivationManager,
+    /// The account-level xpub's parent fingerprint, as hex.
+    fingerprint: String,
+    /// The account-level xpub's derivation depth (0 = master).
+    depth: u8,
+    /// The derivation path used to reach this account, when known.
+    account_path: Option<String>,
 }
 
 #[gen_stub_pymethods]
@@ -44,10 +52,12 @@ impl PyPublicKeyGenerator {
     fn from_xpub(kpub: &str, cosigner_index: Option<u32>) -> PyResult<PyPublicKeyGenerator> {
         let kpub = XPub::try_new(kpub).map_err(|err| PyException::new_err(err.to_string()))?;
         let xpub = kpub.inner();
+        let fingerprint = xpub.attrs().parent_fingerprint.to_vec().to_hex();
+        let depth = xpub.attrs().depth;
         let hd_wallet =
             WalletDerivationManager::from_extended_public_key(xpub.clone(), cosigner_index)
                 .map_err(|err| PyException::new_err(err.to_string()))?;
-        Ok(Self { hd_wallet })
+        Ok(Self { hd_wallet, fingerprint, depth, account_path: None })
     }
 
     /// Create a generator from a master extended private key.
@@ -90,9 +100,16 @@ impl PyPublicKeyGenerator {
             .derive_path(&path)
             .map_err(|err| PyException::new_err(err.to_string()))?;
         let xpub = xprv.public_key();
+        let fingerprint = xpub.attrs().parent_fingerprint.to_vec().to_hex();
+        let depth = xpub.attrs().depth;
         let hd_wallet = WalletDerivationManager::from_extended_public_key(xpub, cosigner_index)
             .map_err(|err| PyException::new_err(err.to_string()))?;
-        Ok(Self { hd_wallet })
+        Ok(Self {
+            hd_wallet,
+            fingerprint,
+            depth,
+            account_path: Some(path.to_string()),
+        })
     }
 
     /// Derive a range of receive (external) public keys.
@@ -555,4 +572,231 @@ impl PyPublicKeyGenerator {
     pub fn to_string(&self) -> PyResult<String> {
         Ok(self.hd_wallet.to_string(None).to_string())
     }
+
+    /// The account-level xpub's parent fingerprint, as hex.
+    ///
+    /// Returns:
+    ///     str: The 4-byte parent fingerprint, hex-encoded.
+    #[getter]
+    pub fn fingerprint(&self) -> String {
+        self.fingerprint.clone()
+    }
+
+    /// The derivation path used to reach this account, when known.
+    ///
+    /// Returns:
+    ///     str | None: The path used in `from_master_xprv` (e.g.
+    ///     `"44'/111111'/0'"`), or None for generators built via `from_xpub`.
+    #[getter]
+    pub fn account_path(&self) -> Option<String> {
+        self.account_path.clone()
+    }
+
+    /// The account-level xpub's derivation depth (0 = master key).
+    ///
+    /// Returns:
+    ///     int: The derivation depth.
+    #[getter]
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Export a ranged output descriptor for this generator's account xpub.
+    ///
+    /// Produces a descriptor of the form `pkh(<xpub>/0/*)` (or `/1/*` when
+    /// `change` is set) with an appended BIP380 checksum, so watch-only
+    /// wallets built with this generator can round-trip through
+    /// descriptor-aware tooling.
+    ///
+    /// Args:
+    ///     network_type: The network type, used to select the xpub prefix.
+    ///     change: Export the change (internal) chain instead of receive (default: False).
+    ///
+    /// Returns:
+    ///     str: The descriptor string, including its `#checksum` suffix.
+    ///
+    /// Raises:
+    ///     Exception: If the xpub cannot be serialized.
+    #[pyo3(signature = (network_type, change=None))]
+    pub fn to_descriptor(
+        &self,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
+        change: Option<bool>,
+    ) -> PyResult<String> {
+        let network_type: NetworkType = network_type.into();
+        let prefix = if network_type == NetworkType::Mainnet {
+            "xpub"
+        } else {
+            "tpub"
+        };
+        let xpub = self
+            .hd_wallet
+            .to_string(Some(prefix.try_into().map_err(|err: kaspa_bip32::Error| {
+                PyException::new_err(err.to_string())
+            })?))
+            .to_string();
+        let chain = if change.unwrap_or(false) { 1 } else { 0 };
+        let body = format!("pkh({xpub}/{chain}/*)");
+        let checksum = descriptor_checksum(&body)?;
+        Ok(format!("{body}#{checksum}"))
+    }
+
+    /// Find the derivation index of a known receive (external) address.
+    ///
+    /// Derives forward from index 0 until the address matches or `max_gap`
+    /// consecutive indexes have been checked without a match.
+    ///
+    /// Args:
+    ///     network_type: The network type used to encode candidate addresses.
+    ///     address: The address to search for.
+    ///     max_gap: Maximum number of indexes to check (default: 20).
+    ///
+    /// Returns:
+    ///     int | None: The matching index, or None if not found within `max_gap`.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    #[pyo3(signature = (network_type, address, max_gap=None))]
+    pub fn find_receive_index(
+        &self,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
+        address: PyAddress,
+        max_gap: Option<u32>,
+    ) -> PyResult<Option<u32>> {
+        self.find_index(network_type.into(), &address.0, max_gap.unwrap_or(20), false)
+    }
+
+    /// Find the derivation index of a known change (internal) address.
+    ///
+    /// Derives forward from index 0 until the address matches or `max_gap`
+    /// consecutive indexes have been checked without a match.
+    ///
+    /// Args:
+    ///     network_type: The network type used to encode candidate addresses.
+    ///     address: The address to search for.
+    ///     max_gap: Maximum number of indexes to check (default: 20).
+    ///
+    /// Returns:
+    ///     int | None: The matching index, or None if not found within `max_gap`.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    #[pyo3(signature = (network_type, address, max_gap=None))]
+    pub fn find_change_index(
+        &self,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
+        address: PyAddress,
+        max_gap: Option<u32>,
+    ) -> PyResult<Option<u32>> {
+        self.find_index(network_type.into(), &address.0, max_gap.unwrap_or(20), true)
+    }
+
+    /// Scan the receive and change chains for used addresses.
+    ///
+    /// Derives each chain from index 0, calling `used_predicate(address_str)`
+    /// for every candidate address, and stops each chain after `gap_limit`
+    /// consecutive addresses are reported unused.
+    ///
+    /// Args:
+    ///     network_type: The network type used to encode candidate addresses.
+    ///     used_predicate: Callable taking an address string and returning
+    ///         whether it has been used.
+    ///     gap_limit: Consecutive unused addresses before stopping a chain (default: 20).
+    ///
+    /// Returns:
+    ///     dict: `{"receive": list[int], "change": list[int]}` of indexes found in use.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails or `used_predicate` raises.
+    #[pyo3(signature = (network_type, used_predicate, gap_limit=None))]
+    pub fn scan<'py>(
+        &self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
+        #[gen_stub(override_type(type_repr = "typing.Callable[[str], bool]"))]
+        used_predicate: Py<PyAny>,
+        gap_limit: Option<u32>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let network_type: NetworkType = network_type.into();
+        let gap_limit = gap_limit.unwrap_or(20);
+
+        let receive = self.scan_chain(py, network_type, &used_predicate, gap_limit, false)?;
+        let change = self.scan_chain(py, network_type, &used_predicate, gap_limit, true)?;
+
+        let dict = PyDict::new(py);
+        dict.set_item("receive", receive)?;
+        dict.set_item("change", change)?;
+        Ok(dict)
+    }
+}
+
+impl PyPublicKeyGenerator {
+    fn find_index(
+        &self,
+        network_type: NetworkType,
+        address: &Address,
+        max_gap: u32,
+        change: bool,
+    ) -> PyResult<Option<u32>> {
+        for index in 0..max_gap {
+            let manager = if change {
+                self.hd_wallet.change_pubkey_manager()
+            } else {
+                self.hd_wallet.receive_pubkey_manager()
+            };
+            let candidate = PublicKey::from(
+                manager
+                    .derive_pubkey(index)
+                    .map_err(|err| PyException::new_err(err.to_string()))?,
+            )
+            .to_address(network_type)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+            if &candidate == address {
+                return Ok(Some(index));
+            }
+        }
+        Ok(None)
+    }
+
+    fn scan_chain(
+        &self,
+        py: Python<'_>,
+        network_type: NetworkType,
+        used_predicate: &Py<PyAny>,
+        gap_limit: u32,
+        change: bool,
+    ) -> PyResult<Vec<u32>> {
+        let mut used_indices = Vec::new();
+        let mut consecutive_unused = 0u32;
+        let mut index = 0u32;
+
+        while consecutive_unused < gap_limit {
+            let manager = if change {
+                self.hd_wallet.change_pubkey_manager()
+            } else {
+                self.hd_wallet.receive_pubkey_manager()
+            };
+            let address = PublicKey::from(
+                manager
+                    .derive_pubkey(index)
+                    .map_err(|err| PyException::new_err(err.to_string()))?,
+            )
+            .to_address(network_type)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+
+            let is_used = used_predicate
+                .call1(py, (address.address_to_string(),))?
+                .extract::<bool>(py)?;
+
+            if is_used {
+                used_indices.push(index);
+                consecutive_unused = 0;
+            } else {
+                consecutive_unused += 1;
+            }
+            index += 1;
+        }
+
+        Ok(used_indices)
+    }
 }
@@ -0,0 +1,186 @@
+use crate::{
+    address::PyAddress,
+    consensus::core::network::PyNetworkType,
+    rpc::wrpc::client::PyRpcClient,
+    wallet::keys::xpub::PyXPub,
+};
+use kaspa_consensus_core::network::NetworkType;
+use kaspa_rpc_core::message::GetBalancesByAddressesRequest;
+use pyo3::{exceptions::PyException, prelude::*, types::PyDict};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+
+/// Derive the address at `branch`/`index` below an account-level `XPub`,
+/// following the standard BIP-44 `.../<branch>/<index>` layout (branch `0`
+/// is the receive chain, `1` is the change chain).
+fn derive_address(xpub: &PyXPub, network: NetworkType, branch: u32, index: u32) -> PyResult<PyAddress> {
+    let public_key = xpub
+        .derive_child(branch, Some(false))?
+        .derive_child(index, Some(false))?
+        .public_key();
+    let address = public_key
+        .0
+        .to_address(network)
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    Ok(PyAddress(address))
+}
+
+/// A watch-only account that can discover its used addresses and balances
+/// from a plain `XPub`, without ever seeing a private key.
+///
+/// Built on `XPub.derive_child`, this enumerates the receive (branch 0) and
+/// change (branch 1) chains of an account-level extended public key and,
+/// given a connected `RpcClient`, performs standard BIP-44 gap-limit
+/// discovery: addresses are queried in `gap_limit`-sized batches per chain,
+/// and a chain stops being extended once `gap_limit` consecutive addresses
+/// come back with no balance.
+///
+/// Category: Wallet/Keys
+#[gen_stub_pyclass]
+#[pyclass(name = "WatchOnlyAccount")]
+#[derive(Clone)]
+pub struct PyWatchOnlyAccount {
+    xpub: PyXPub,
+    network: NetworkType,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyWatchOnlyAccount {
+    /// Create a watch-only account from an account-level XPub.
+    ///
+    /// Args:
+    ///     xpub: The account-level extended public key (e.g. at `m/44'/111111'/0'`).
+    ///     network: The network type used to encode derived addresses.
+    ///
+    /// Returns:
+    ///     WatchOnlyAccount: A new watch-only account.
+    #[new]
+    pub fn new(
+        xpub: PyXPub,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network: PyNetworkType,
+    ) -> Self {
+        Self { xpub, network: network.into() }
+    }
+
+    /// Derive the receive address at the given index.
+    ///
+    /// Args:
+    ///     index: The address index.
+    ///
+    /// Returns:
+    ///     Address: The derived receive address.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    pub fn receive_address(&self, index: u32) -> PyResult<PyAddress> {
+        derive_address(&self.xpub, self.network, 0, index)
+    }
+
+    /// Derive the change address at the given index.
+    ///
+    /// Args:
+    ///     index: The address index.
+    ///
+    /// Returns:
+    ///     Address: The derived change address.
+    ///
+    /// Raises:
+    ///     Exception: If derivation fails.
+    pub fn change_address(&self, index: u32) -> PyResult<PyAddress> {
+        derive_address(&self.xpub, self.network, 1, index)
+    }
+
+    /// Discover used addresses and their balances via gap-limit scanning.
+    ///
+    /// Derives the receive and change chains in `gap_limit`-sized batches,
+    /// querying each batch's balances in a single round trip, and keeps
+    /// extending a chain until `gap_limit` consecutive addresses come back
+    /// with a zero balance.
+    ///
+    /// Args:
+    ///     rpc_client: A connected RpcClient to query balances with.
+    ///     gap_limit: Consecutive unused addresses before stopping a chain (default: 20).
+    ///
+    /// Returns:
+    ///     dict: `{"addresses": list[dict], "receive_next_index": int,
+    ///     "change_next_index": int}`, where each address entry is
+    ///     `{"address": str, "branch": "receive" | "change", "index": int, "balance": int}`.
+    ///
+    /// Raises:
+    ///     Exception: If derivation or an RPC query fails.
+    ///
+    /// Category: Wallet/Keys
+    #[pyo3(signature = (rpc_client, gap_limit=None))]
+    fn scan<'py>(
+        &self,
+        py: Python<'py>,
+        rpc_client: PyRpcClient,
+        gap_limit: Option<u32>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let gap_limit = gap_limit.unwrap_or(20).max(1);
+        let xpub = self.xpub.clone();
+        let network = self.network;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut addresses = Vec::new();
+            let mut next_index = [0u32; 2];
+
+            for (branch, label) in [(0u32, "receive"), (1u32, "change")] {
+                let mut index = 0u32;
+                let mut consecutive_unused = 0u32;
+                let mut last_used_index: Option<u32> = None;
+
+                while consecutive_unused < gap_limit {
+                    let batch: Vec<u32> = (index..index + gap_limit).collect();
+                    let batch_addresses = batch
+                        .iter()
+                        .map(|&i| derive_address(&xpub, network, branch, i).map(|a| a.into()))
+                        .collect::<PyResult<Vec<kaspa_addresses::Address>>>()?;
+
+                    let request = GetBalancesByAddressesRequest { addresses: batch_addresses.clone() };
+                    let response = rpc_client
+                        .client()
+                        .get_balances_by_addresses_call(None, request)
+                        .await
+                        .map_err(|err| PyException::new_err(err.to_string()))?;
+
+                    for (offset, entry) in response.entries.into_iter().enumerate() {
+                        let balance = entry.balance.unwrap_or(0);
+                        let current_index = index + offset as u32;
+                        if balance > 0 {
+                            addresses.push((label, current_index, batch_addresses[offset].clone(), balance));
+                            last_used_index = Some(current_index);
+                            consecutive_unused = 0;
+                        } else {
+                            consecutive_unused += 1;
+                        }
+                        if consecutive_unused >= gap_limit {
+                            break;
+                        }
+                    }
+
+                    index += gap_limit;
+                }
+
+                next_index[branch as usize] = last_used_index.map(|i| i + 1).unwrap_or(0);
+            }
+
+            Python::attach(|py| {
+                let result = PyDict::new(py);
+                let address_list = pyo3::types::PyList::empty(py);
+                for (branch, index, address, balance) in addresses {
+                    let entry = PyDict::new(py);
+                    entry.set_item("address", address.to_string())?;
+                    entry.set_item("branch", branch)?;
+                    entry.set_item("index", index)?;
+                    entry.set_item("balance", balance)?;
+                    address_list.append(entry)?;
+                }
+                result.set_item("addresses", address_list)?;
+                result.set_item("receive_next_index", next_index[0])?;
+                result.set_item("change_next_index", next_index[1])?;
+                Ok(result.unbind())
+            })
+        })
+    }
+}
@@ -1,3 +1,4 @@
+use crate::crypto::{keyfile, keystore};
 use crate::wallet::keys::derivation::PyDerivationPath;
 use crate::wallet::keys::{privatekey::PyPrivateKey, xpub::PyXPub};
 use kaspa_bip32::Error;
@@ -42,7 +43,7 @@ impl PyXPrv {
     /// Raises:
     ///     Exception: If the seed is invalid.
     #[new]
-    fn try_new(seed: &str) -> PyResult<PyXPrv> {
+    pub(crate) fn try_new(seed: &str) -> PyResult<PyXPrv> {
         let seed_bytes = Vec::<u8>::from_hex(seed)
             .map_err(|e| PyErr::new::<PyException, _>(format!("{}", e)))?;
 
@@ -246,4 +247,75 @@ impl PyXPrv {
     pub fn get_chain_code(&self) -> String {
         self.0.attrs().chain_code.to_vec().to_hex()
     }
+
+    /// Encrypt this key into a portable keystore blob.
+    ///
+    /// Derives a key from `password` via PBKDF2-HMAC-SHA512 with a random
+    /// salt, then encrypts the serialized `kprv` string with AES-256-GCM.
+    /// The result is a self-describing JSON envelope safe to persist at rest.
+    ///
+    /// Args:
+    ///     password: The password to encrypt with.
+    ///
+    /// Returns:
+    ///     str: The JSON-encoded keystore envelope.
+    ///
+    /// Raises:
+    ///     Exception: If serialization or encryption fails.
+    pub fn to_encrypted(&self, password: &str) -> PyResult<String> {
+        keystore::encrypt(self.to_string()?.as_bytes(), password)
+    }
+
+    /// Decrypt a keystore blob produced by `to_encrypted`.
+    ///
+    /// Args:
+    ///     blob: The JSON-encoded keystore envelope.
+    ///     password: The password it was encrypted with.
+    ///
+    /// Returns:
+    ///     XPrv: The decrypted extended private key.
+    ///
+    /// Raises:
+    ///     Exception: If the envelope is malformed or `password` is wrong.
+    #[staticmethod]
+    pub fn from_encrypted(blob: &str, password: &str) -> PyResult<PyXPrv> {
+        let plaintext = keystore::decrypt(blob, password)?;
+        let xprv = String::from_utf8(plaintext)
+            .map_err(|err| PyException::new_err(format!("Decrypted keystore is not valid UTF-8: {err}")))?;
+        PyXPrv::from_xprv_str(&xprv)
+    }
+
+    /// Save this key to a file.
+    ///
+    /// Args:
+    ///     path: The file path to write to.
+    ///     password: Optional password; if given, the file is encrypted
+    ///         with the same format as `to_encrypted`. Otherwise the `kprv`
+    ///         string is stored in plain text.
+    ///
+    /// Raises:
+    ///     Exception: If serialization or writing the file fails.
+    #[pyo3(signature = (path, password=None))]
+    pub fn write_to_file(&self, path: &str, password: Option<&str>) -> PyResult<()> {
+        keyfile::write_to_file(path, "XPrv", &self.to_string()?, password)
+    }
+
+    /// Load a key previously saved with `write_to_file`.
+    ///
+    /// Args:
+    ///     path: The file path to read from.
+    ///     password: The password, if the file is encrypted.
+    ///
+    /// Returns:
+    ///     XPrv: The loaded extended private key.
+    ///
+    /// Raises:
+    ///     Exception: If the file is missing, not an `XPrv` key file, or
+    ///         `password` is required/wrong.
+    #[staticmethod]
+    #[pyo3(signature = (path, password=None))]
+    pub fn read_from_file(path: &str, password: Option<&str>) -> PyResult<PyXPrv> {
+        let xprv = keyfile::read_from_file(path, "XPrv", password)?;
+        PyXPrv::from_xprv_str(&xprv)
+    }
 }
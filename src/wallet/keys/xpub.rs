@@ -154,6 +154,24 @@ impl PyXPub {
         self.0.inner().attrs().child_number.into()
     }
 
+    /// The child number, formatted like a BIP32 key-dump utility.
+    ///
+    /// Hardened indices (raw value >= 0x80000000) are reported as
+    /// `"{index}H ({raw})"`, e.g. `"0H (2147483648)"`; normal indices are
+    /// reported as their plain decimal value.
+    ///
+    /// Returns:
+    ///     str: The formatted child index.
+    pub fn child_index(&self) -> String {
+        const HARDENED_FLAG: u32 = 0x80000000;
+        let raw: u32 = self.0.inner().attrs().child_number.into();
+        if raw >= HARDENED_FLAG {
+            format!("{}H ({})", raw - HARDENED_FLAG, raw)
+        } else {
+            raw.to_string()
+        }
+    }
+
     /// The chain code as hex.
     #[getter]
     pub fn get_chain_code(&self) -> String {
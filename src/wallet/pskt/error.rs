@@ -21,15 +21,43 @@ impl PyPsktCustomError {
 
 // #[gen_stub_pyclass]
 // create_exception!("kaspa.exceptions", PsktCustomError, PyException);
-create_exception!("kaspa.exceptions", PsktStateError, PyException);
-create_exception!("kaspa.exceptions", PsktExpectedStateError, PyException);
-create_exception!("kaspa.exceptions", PsktCtorError, PyException);
-create_exception!("kaspa.exceptions", PsktInvalidPayloadError, PyException);
-create_exception!("kaspa.exceptions", PsktTxNotFinalizedError, PyException);
-create_exception!("kaspa.exceptions", PsktCreateNotAllowedError, PyException);
-create_exception!("kaspa.exceptions", PsktNotInitializedError, PyException);
-create_exception!("kaspa.exceptions", PsktConsensusClientError, PyException);
-create_exception!("kaspa.exceptions", PsktError, PyException);
+
+/// Common base for every structured PSKT exception below.
+///
+/// Lets Python callers write `except KaspaError` to catch any of the
+/// specific Pskt*Error subclasses without string-matching messages.
+create_exception!("kaspa.exceptions", KaspaError, PyException);
+
+/// Declares a `KaspaError` subclass for one PSKT error variant, with a
+/// stable, machine-readable `code` string attached as the second element of
+/// `err.args` (`err.args == (message, code)`).
+///
+/// This crate has no proc-macro sub-crate, so a declarative macro stands in
+/// for the attribute-macro approach other SDKs use to generate a typed
+/// exception surface from an error enum.
+macro_rules! kaspa_error {
+    ($name:ident, $code:literal) => {
+        create_exception!("kaspa.exceptions", $name, KaspaError);
+
+        impl $name {
+            pub const CODE: &'static str = $code;
+
+            pub fn new_err(message: impl Into<String>) -> PyErr {
+                PyErr::new::<Self, _>((message.into(), $code))
+            }
+        }
+    };
+}
+
+kaspa_error!(PsktStateError, "pskt.state");
+kaspa_error!(PsktExpectedStateError, "pskt.expected_state");
+kaspa_error!(PsktCtorError, "pskt.ctor");
+kaspa_error!(PsktInvalidPayloadError, "pskt.invalid_payload");
+kaspa_error!(PsktTxNotFinalizedError, "pskt.tx_not_finalized");
+kaspa_error!(PsktCreateNotAllowedError, "pskt.create_not_allowed");
+kaspa_error!(PsktNotInitializedError, "pskt.not_initialized");
+kaspa_error!(PsktConsensusClientError, "pskt.consensus_client");
+kaspa_error!(PsktError, "pskt.generic");
 
 impl From<PyPsktError> for PyErr {
     fn from(value: PyPsktError) -> Self {
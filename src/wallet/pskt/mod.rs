@@ -1,15 +1,24 @@
 pub mod error;
 
-use crate::consensus::client::transaction::PyTransaction;
+use base64::Engine as _;
+use crate::{consensus::client::transaction::PyTransaction, wallet::keys::privatekey::PyPrivateKey};
 use kaspa_consensus_client::Transaction;
+use kaspa_consensus_core::{
+    hashing::{sig::calc_schnorr_signature_hash, sighash::SigHashReusedValuesUnsync, sighash_type::SIG_HASH_ALL},
+    sign::sign_input,
+    tx::SignableTransaction,
+};
 use kaspa_wallet_pskt::{
-    pskt::{Inner, PSKT},
+    pskt::{Inner, PSKT, SignInputOk},
     role::*,
     wasm::pskt::State,
 };
-use pyo3::{exceptions::PyException, prelude::*};
+use pyo3::{exceptions::PyException, prelude::*, types::PyList};
 use pyo3_stub_gen::derive::*;
+use secp256k1::{Keypair, PublicKey};
 use std::sync::{Arc, Mutex};
+use workflow_core::hex::ToHex;
+use zeroize::Zeroize;
 
 /// Partially Signed Kaspa Transaction
 #[gen_stub_pyclass]
@@ -24,10 +33,15 @@ pub struct PyPSKT {
 impl PyPSKT {
     #[new]
     pub fn new(payload: Bound<'_, PyAny>) -> PyResult<Self> {
-        let payload = if let Ok(p) = payload.extract::<String>() {
-            let inner =
-                serde_json::from_str(&p).map_err(|err| PyException::new_err(err.to_string()))?;
-            Ok(PyPSKT::from(State::NoOp(Some(inner))))
+        let payload = if let Ok(bytes) = payload.extract::<Vec<u8>>() {
+            Self::from_bytes(bytes)
+        } else if let Ok(p) = payload.extract::<String>() {
+            if let Ok(inner) = serde_json::from_str(&p) {
+                Ok(PyPSKT::from(State::NoOp(Some(inner))))
+            } else {
+                // Not JSON: fall back to the compact base64 transport format.
+                Self::from_base64(p)
+            }
         } else if let Ok(py_tx) = payload.extract::<PyTransaction>() {
             let tx: Transaction = py_tx.into();
             let inner: Inner = tx
@@ -59,6 +73,49 @@ impl PyPSKT {
         serde_json::to_string(state.as_ref().unwrap()).unwrap()
     }
 
+    /// Encode this PSKT as a compact, length-prefixed binary blob (a 4-byte
+    /// little-endian length followed by the Borsh-encoded state), for
+    /// handoff between signing parties over channels where JSON is too
+    /// large or awkward (QR codes, URIs, file exchange).
+    pub fn to_bytes(&self) -> PyResult<Vec<u8>> {
+        let state = self.state();
+        let body = borsh::to_vec(state.as_ref().unwrap())
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let mut out = Vec::with_capacity(4 + body.len());
+        out.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        out.extend_from_slice(&body);
+        Ok(out)
+    }
+
+    /// Base64 encoding of `to_bytes()`, for transport as plain text.
+    pub fn to_base64(&self) -> PyResult<String> {
+        Ok(base64::engine::general_purpose::STANDARD.encode(self.to_bytes()?))
+    }
+
+    /// Decode a PSKT previously produced by `to_base64()`.
+    #[staticmethod]
+    pub fn from_base64(encoded: String) -> PyResult<PyPSKT> {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        Self::from_bytes(bytes)
+    }
+
+    /// Decode a PSKT previously produced by `to_bytes()`.
+    #[staticmethod]
+    pub fn from_bytes(bytes: Vec<u8>) -> PyResult<PyPSKT> {
+        let length_prefix = bytes
+            .get(0..4)
+            .ok_or_else(|| PyException::new_err("PSKT binary payload is too short"))?;
+        let length = u32::from_le_bytes(length_prefix.try_into().unwrap()) as usize;
+        let body = bytes
+            .get(4..4 + length)
+            .ok_or_else(|| PyException::new_err("PSKT binary payload length prefix doesn't match its body"))?;
+        let state: State =
+            borsh::from_slice(body).map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(PyPSKT::from(state))
+    }
+
     fn state(&self) -> MutexGuard<'_, Option<State>> {
         self.state.lock().unwrap()
     }
@@ -248,6 +305,87 @@ impl PyPSKT {
         self.replace(state)
     }
 
+    /// Sign every signable input, valid only in `State::Signer`.
+    ///
+    /// Args:
+    ///     keys: Either a list of `PrivateKey` objects (each is tried against
+    ///         every input; a key that doesn't unlock a given input simply
+    ///         contributes no signature for it, which is what makes partial
+    ///         multisig signing work) or a Python callable
+    ///         `(input_index, sighash) -> (public_key_hex, signature_hex)`
+    ///         for custom or hardware-wallet signing flows.
+    ///
+    /// Returns:
+    ///     PSKT: The updated PSKT, still in the `Signer` role, with partial
+    ///     signatures recorded against each signable input.
+    ///
+    /// Raises:
+    ///     Exception: If not in the `Signer` role, or if signing fails.
+    pub fn sign(&self, py: Python<'_>, keys: Bound<'_, PyAny>) -> PyResult<PyPSKT> {
+        let (private_keys, callback) = self.parse_signers(keys)?;
+        self.sign_impl(py, private_keys, callback, None)
+    }
+
+    /// Sign a single input by index, valid only in `State::Signer`.
+    ///
+    /// Args:
+    ///     index: The input index to sign.
+    ///     key: The private key to sign with.
+    ///
+    /// Returns:
+    ///     PSKT: The updated PSKT, still in the `Signer` role.
+    ///
+    /// Raises:
+    ///     Exception: If not in the `Signer` role, or if signing fails.
+    pub fn sign_input(&self, py: Python<'_>, index: usize, key: &PyPrivateKey) -> PyResult<PyPSKT> {
+        self.sign_impl(py, Some(vec![key.secret_bytes()]), None, Some(index))
+    }
+
+    /// Merge another independently-signed PSKT into this one, valid only in
+    /// `State::Combiner`.
+    ///
+    /// Unions the per-input signature maps and redeem scripts of `other`
+    /// into this PSKT, after checking the two share the same underlying
+    /// transaction. This is the multi-signer coordination step: each signer
+    /// hands back their own signed PSKT, and one party combines them all
+    /// before finalizing.
+    ///
+    /// Args:
+    ///     other: The other signer's PSKT, in the `Signer` or `Combiner` role.
+    ///
+    /// Returns:
+    ///     PSKT: This PSKT, still in the `Combiner` role, with `other`'s
+    ///     signatures merged in.
+    ///
+    /// Raises:
+    ///     Exception: If either PSKT is not in a combinable role, or the two
+    ///         PSKTs don't share the same underlying transaction.
+    pub fn combine(&self, other: &PyPSKT) -> PyResult<PyPSKT> {
+        let pskt = match self.take() {
+            State::Combiner(pskt) => pskt,
+            state => {
+                self.replace(state)?;
+                return Err(Error::expected_state("Combiner").into());
+            }
+        };
+
+        let other_state = other
+            .state()
+            .clone()
+            .ok_or_else(|| PyException::new_err("other PSKT has no state"))?;
+        let other_pskt = match other_state {
+            State::Combiner(pskt) => pskt,
+            State::Signer(pskt) => pskt.combiner(),
+            _ => {
+                self.replace(State::Combiner(pskt))?;
+                return Err(Error::expected_state("Combiner or Signer").into());
+            }
+        };
+
+        let merged = (pskt + other_pskt).map_err(Error::custom)?;
+        self.replace(State::Combiner(merged))
+    }
+
     #[wasm_bindgen(js_name = calculateId)]
     pub fn calculate_id(&self) -> Result<TransactionId> {
         let state = self.state();
@@ -267,6 +405,47 @@ impl PyPSKT {
 
         let network_id = NetworkType::from_str(&network_id).map_err(|e| Error::custom(format!("Invalid networkId: {}", e)))?;
 
+        // Per-input placeholder descriptions for inputs carrying a redeem script
+        // (P2SH / multisig), keyed by input index, so the dummy-finalize step
+        // below can size their unlock data instead of refusing them outright.
+        let redeem_placeholders = js_sys::Reflect::get(&obj, &"inputs".into())
+            .ok()
+            .filter(|v| !v.is_undefined() && !v.is_null())
+            .map(|inputs| -> Result<std::collections::HashMap<usize, Vec<u8>>> {
+                let inputs = js_sys::Array::from(&inputs);
+                let mut placeholders = std::collections::HashMap::new();
+                for entry in inputs.iter() {
+                    let entry_obj = js_sys::Object::from(entry);
+                    let index = js_sys::Reflect::get(&entry_obj, &"index".into())
+                        .map_err(|_| Error::custom("input descriptor is missing `index`"))?
+                        .as_f64()
+                        .ok_or_else(|| Error::custom("`index` must be a number"))? as usize;
+
+                    let script_sig = js_sys::Reflect::get(&entry_obj, &"scriptSig".into())
+                        .ok()
+                        .and_then(|v| v.as_string());
+
+                    let placeholder = if let Some(script_sig) = script_sig {
+                        hex::decode(script_sig).map_err(|e| Error::custom(format!("`scriptSig` is not a hex string: {e}")))?
+                    } else {
+                        let num_signatures = js_sys::Reflect::get(&entry_obj, &"numSignatures".into())
+                            .ok()
+                            .and_then(|v| v.as_f64())
+                            .unwrap_or(1.0) as usize;
+                        let redeem_script_length = js_sys::Reflect::get(&entry_obj, &"redeemScriptLength".into())
+                            .ok()
+                            .and_then(|v| v.as_f64())
+                            .unwrap_or(0.0) as usize;
+                        dummy_redeem_unlock_script(num_signatures, redeem_script_length)
+                    };
+
+                    placeholders.insert(index, placeholder);
+                }
+                Ok(placeholders)
+            })
+            .transpose()?
+            .unwrap_or_default();
+
         let cloned_pskt = self.clone();
 
         let extractor = {
@@ -276,13 +455,19 @@ impl PyPSKT {
 
             match finalizer_state {
                 State::Finalizer(pskt) => {
-                    for input in pskt.inputs.iter() {
-                        if input.redeem_script.is_some() {
-                            return Err(Error::custom("Mass calculation is not supported for inputs with redeem scripts"));
+                    for (index, input) in pskt.inputs.iter().enumerate() {
+                        if input.redeem_script.is_some() && !redeem_placeholders.contains_key(&index) {
+                            return Err(Error::custom(format!(
+                                "Mass calculation for input {index} with a redeem script requires a placeholder description in `inputs`"
+                            )));
                         }
                     }
                     let pskt = pskt
-                        .finalize_sync(|inner: &Inner| -> Result<Vec<Vec<u8>>> { Ok(vec![vec![0u8, 65]; inner.inputs.len()]) })
+                        .finalize_sync(|inner: &Inner| -> Result<Vec<Vec<u8>>> {
+                            Ok((0..inner.inputs.len())
+                                .map(|index| redeem_placeholders.get(&index).cloned().unwrap_or_else(|| vec![0u8, 65]))
+                                .collect())
+                        })
                         .map_err(|e| Error::custom(format!("Failed to finalize PSKT: {e}")))?;
                     pskt.extractor()?
                 }
@@ -296,6 +481,128 @@ impl PyPSKT {
     }
 }
 
+/// A dummy unlock script sized like a real P2SH/multisig spend, for mass
+/// estimation of inputs that cannot be finalized yet.
+///
+/// Lays out `num_signatures` placeholder Schnorr signatures (each a 1-byte
+/// push length, 64 sig bytes, 1 sighash-type byte) followed by a push of the
+/// redeem script itself, matching the standard `<sig>... <redeem_script>`
+/// unlock shape so the resulting transaction mass reflects the real spend.
+fn dummy_redeem_unlock_script(num_signatures: usize, redeem_script_length: usize) -> Vec<u8> {
+    const SIGNATURE_PLACEHOLDER_LEN: usize = 66;
+    let push_overhead = if redeem_script_length <= 0x4b {
+        1
+    } else if redeem_script_length <= 0xff {
+        2
+    } else if redeem_script_length <= 0xffff {
+        3
+    } else {
+        5
+    };
+    vec![0u8; num_signatures * SIGNATURE_PLACEHOLDER_LEN + push_overhead + redeem_script_length]
+}
+
+impl PyPSKT {
+    /// Splits the `keys` argument of `sign()` into either a list of raw
+    /// private key bytes, or a Python callback, never both.
+    fn parse_signers(
+        &self,
+        keys: Bound<'_, PyAny>,
+    ) -> PyResult<(Option<Vec<[u8; 32]>>, Option<Py<PyAny>>)> {
+        if keys.is_callable() {
+            Ok((None, Some(keys.unbind())))
+        } else {
+            let list: Bound<'_, PyList> = keys.cast_into().map_err(|_| {
+                PyException::new_err("keys must be a list of PrivateKey objects or a callable")
+            })?;
+            let mut out = Vec::with_capacity(list.len());
+            for item in list.iter() {
+                let key: PyRef<'_, PyPrivateKey> = item.extract()?;
+                out.push(key.secret_bytes());
+            }
+            Ok((Some(out), None))
+        }
+    }
+
+    /// Shared implementation behind `sign()` and `sign_input()`.
+    ///
+    /// When `only_index` is `Some`, every input other than that one is left
+    /// untouched so selective, single-input signing doesn't disturb
+    /// signatures already collected for the rest of the transaction.
+    fn sign_impl(
+        &self,
+        py: Python<'_>,
+        private_keys: Option<Vec<[u8; 32]>>,
+        callback: Option<Py<PyAny>>,
+        only_index: Option<usize>,
+    ) -> PyResult<PyPSKT> {
+        let pskt = match self.take() {
+            State::Signer(pskt) => pskt,
+            state => {
+                self.replace(state)?;
+                return Err(Error::expected_state("Signer").into());
+            }
+        };
+
+        let reused_values = SigHashReusedValuesUnsync::new();
+
+        let pskt = pskt
+            .pass_signature_sync(|_inner: &Inner, signables: Vec<SignableTransaction>| {
+                signables
+                    .iter()
+                    .enumerate()
+                    .map(|(index, signable)| -> std::result::Result<Vec<SignInputOk>, String> {
+                        if only_index.is_some_and(|only| only != index) {
+                            return Ok(vec![]);
+                        }
+
+                        if let Some(keys) = &private_keys {
+                            let mut results = Vec::with_capacity(keys.len());
+                            for key in keys {
+                                let mut key_bytes = *key;
+                                let keypair =
+                                    Keypair::from_seckey_slice(secp256k1::SECP256K1, &key_bytes)
+                                        .map_err(|err| err.to_string())?;
+                                let signature = sign_input(signable, index, &key_bytes, SIG_HASH_ALL);
+                                key_bytes.zeroize();
+                                results.push(SignInputOk {
+                                    signature,
+                                    pub_key: keypair.public_key(),
+                                    key_source: None,
+                                });
+                            }
+                            Ok(results)
+                        } else {
+                            let callback = callback.as_ref().unwrap();
+                            let sighash = calc_schnorr_signature_hash(signable, index, SIG_HASH_ALL, &reused_values);
+                            Python::attach(|py| -> std::result::Result<Vec<SignInputOk>, String> {
+                                let (pub_key_hex, signature_hex): (String, String) = callback
+                                    .call1(py, (index, sighash.as_bytes().to_vec().to_hex()))
+                                    .map_err(|err| err.to_string())?
+                                    .extract(py)
+                                    .map_err(|err| err.to_string())?;
+                                let pub_key = PublicKey::from_slice(
+                                    &hex::decode(pub_key_hex).map_err(|err| err.to_string())?,
+                                )
+                                .map_err(|err| err.to_string())?;
+                                let signature = hex::decode(signature_hex).map_err(|err| err.to_string())?;
+                                Ok(vec![SignInputOk {
+                                    signature,
+                                    pub_key,
+                                    key_source: None,
+                                }])
+                            })
+                        }
+                    })
+                    .collect()
+            })
+            .map_err(Error::custom)?;
+
+        let _ = py;
+        self.replace(State::Signer(pskt))
+    }
+}
+
 impl From<State> for PyPSKT {
     fn from(value: State) -> Self {
         PyPSKT {